@@ -0,0 +1,317 @@
+//! Positions of the sun and moon, after Jean Meeus, *Astronomical
+//! Algorithms* (2nd ed., 1998).
+//!
+//! The series are truncated: the sun is good to about 0.01° and the moon to
+//! about 0.005°, which places new moons and tithi boundaries within a couple
+//! of minutes and sunrise within a minute for modern dates. Instants are
+//! Julian Days in Universal Time unless noted otherwise.
+
+use chrono::{DateTime, NaiveDate, NaiveTime, Utc};
+
+/// The Julian Day of 1 January 2000, 12:00 TT.
+const J2000: f64 = 2451545.0;
+/// The Julian Day of the Unix epoch.
+const UNIX_EPOCH: f64 = 2440587.5;
+/// The mean time from one new moon to the next, in days.
+pub(crate) const SYNODIC_MONTH: f64 = 29.530588861;
+/// The new moon of 6 January 2000, in Universal Time.
+const NEW_MOON_2000: f64 = 2451550.09766;
+/// The altitude of the sun's centre at sunrise, allowing for refraction and
+/// the sun's radius.
+const SUNRISE_ALTITUDE: f64 = -0.8333;
+
+/// Periodic terms for the moon's longitude, as multiples of D, M, M' and F
+/// with coefficients in millionths of a degree (Meeus, table 47.A).
+#[rustfmt::skip]
+const MOON_LONGITUDE_TERMS: [(i8, i8, i8, i8, i32); 59] = [
+    (0, 0, 1, 0, 6288774), (2, 0, -1, 0, 1274027), (2, 0, 0, 0, 658314),
+    (0, 0, 2, 0, 213618), (0, 1, 0, 0, -185116), (0, 0, 0, 2, -114332),
+    (2, 0, -2, 0, 58793), (2, -1, -1, 0, 57066), (2, 0, 1, 0, 53322),
+    (2, -1, 0, 0, 45758), (0, 1, -1, 0, -40923), (1, 0, 0, 0, -34720),
+    (0, 1, 1, 0, -30383), (2, 0, 0, -2, 15327), (0, 0, 1, 2, -12528),
+    (0, 0, 1, -2, 10980), (4, 0, -1, 0, 10675), (0, 0, 3, 0, 10034),
+    (4, 0, -2, 0, 8548), (2, 1, -1, 0, -7888), (2, 1, 0, 0, -6766),
+    (1, 0, -1, 0, -5163), (1, 1, 0, 0, 4987), (2, -1, 1, 0, 4036),
+    (2, 0, 2, 0, 3994), (4, 0, 0, 0, 3861), (2, 0, -3, 0, 3665),
+    (0, 1, -2, 0, -2689), (2, 0, -1, 2, -2602), (2, -1, -2, 0, 2390),
+    (1, 0, 1, 0, -2348), (2, -2, 0, 0, 2236), (0, 1, 2, 0, -2120),
+    (0, 2, 0, 0, -2069), (2, -2, -1, 0, 2048), (2, 0, 1, -2, -1773),
+    (2, 0, 0, 2, -1595), (4, -1, -1, 0, 1215), (0, 0, 2, 2, -1110),
+    (3, 0, -1, 0, -892), (2, 1, 1, 0, -810), (4, -1, -2, 0, 759),
+    (0, 2, -1, 0, -713), (2, 2, -1, 0, -700), (2, 1, -2, 0, 691),
+    (2, -1, 0, -2, 596), (4, 0, 1, 0, 549), (0, 0, 4, 0, 537),
+    (4, -1, 0, 0, 520), (1, 0, -2, 0, -487), (2, 1, 0, -2, -399),
+    (0, 0, 2, -2, -381), (1, 1, 1, 0, 351), (3, 0, -2, 0, -340),
+    (4, 0, -3, 0, 330), (2, -1, 2, 0, 327), (0, 2, 1, 0, -323),
+    (1, 1, -1, 0, 299), (2, 0, 3, 0, 294),
+];
+
+/// The Julian Day of an instant.
+pub(crate) fn julian_day(instant: DateTime<Utc>) -> f64 {
+    UNIX_EPOCH + instant.timestamp_millis() as f64 / 86_400_000.0
+}
+
+/// The instant of a Julian Day, rounded to the millisecond, or `None` if it
+/// is outside the range of chrono.
+pub(crate) fn from_julian_day(julian_day: f64) -> Option<DateTime<Utc>> {
+    let millis: f64 = ((julian_day - UNIX_EPOCH) * 86_400_000.0).round();
+    if !millis.is_finite() || millis.abs() > i64::MAX as f64 {
+        return None;
+    }
+    DateTime::from_timestamp_millis(millis as i64)
+}
+
+/// The Julian Day at the given UTC time on a date.
+pub(crate) fn julian_day_at(date: NaiveDate, time: NaiveTime) -> f64 {
+    julian_day(date.and_time(time).and_utc())
+}
+
+/// The difference between Terrestrial Time and Universal Time, in days.
+///
+/// Uses the polynomials of Espenak and Meeus around the present and the
+/// long-term parabola of Morrison and Stephenson elsewhere.
+fn delta_t(julian_day: f64) -> f64 {
+    let year: f64 = 2000.0 + (julian_day - J2000) / 365.2425;
+    let seconds: f64 = if (1986.0..2005.0).contains(&year) {
+        let t: f64 = year - 2000.0;
+        63.86 + 0.3345 * t - 0.060374 * t.powi(2)
+            + 0.0017275 * t.powi(3)
+            + 0.000651814 * t.powi(4)
+            + 0.00002373599 * t.powi(5)
+    } else if (2005.0..2050.0).contains(&year) {
+        let t: f64 = year - 2000.0;
+        62.92 + 0.32217 * t + 0.005589 * t.powi(2)
+    } else {
+        let u: f64 = (year - 1820.0) / 100.0;
+        -20.0 + 32.0 * u.powi(2)
+    };
+    seconds / 86_400.0
+}
+
+/// Julian centuries of Terrestrial Time since J2000 at a Universal Time.
+fn centuries(julian_day: f64) -> f64 {
+    (julian_day + delta_t(julian_day) - J2000) / 36525.0
+}
+
+/// Reduces an angle to the range [0, 360).
+pub(crate) fn normalize(degrees: f64) -> f64 {
+    degrees.rem_euclid(360.0)
+}
+
+/// Reduces an angle to the range [-180, 180).
+fn normalize_signed(degrees: f64) -> f64 {
+    (degrees + 180.0).rem_euclid(360.0) - 180.0
+}
+
+/// The nutation in longitude, in degrees, to its largest term.
+fn nutation(t: f64) -> f64 {
+    let omega: f64 = 125.04452 - 1934.136261 * t;
+    -0.00478 * omega.to_radians().sin()
+}
+
+/// The sun's apparent tropical longitude, in degrees (Meeus, chapter 25).
+pub(crate) fn sun_longitude(julian_day: f64) -> f64 {
+    let t: f64 = centuries(julian_day);
+    let mean_longitude: f64 = 280.46646 + 36000.76983 * t + 0.0003032 * t.powi(2);
+    let anomaly: f64 = (357.52911 + 35999.05029 * t - 0.0001537 * t.powi(2)).to_radians();
+    let centre: f64 = (1.914602 - 0.004817 * t - 0.000014 * t.powi(2)) * anomaly.sin()
+        + (0.019993 - 0.000101 * t) * (2.0 * anomaly).sin()
+        + 0.000289 * (3.0 * anomaly).sin();
+    normalize(mean_longitude + centre - 0.00569 + nutation(t))
+}
+
+/// The moon's apparent tropical longitude, in degrees (Meeus, chapter 47).
+pub(crate) fn moon_longitude(julian_day: f64) -> f64 {
+    let t: f64 = centuries(julian_day);
+    let mean_longitude: f64 = 218.3164477 + 481267.88123421 * t - 0.0015786 * t.powi(2)
+        + t.powi(3) / 538841.0
+        - t.powi(4) / 65194000.0;
+    let elongation: f64 = 297.8501921 + 445267.1114034 * t - 0.0018819 * t.powi(2)
+        + t.powi(3) / 545868.0
+        - t.powi(4) / 113065000.0;
+    let sun_anomaly: f64 = 357.5291092 + 35999.0502909 * t - 0.0001536 * t.powi(2);
+    let moon_anomaly: f64 =
+        134.9633964 + 477198.8675055 * t + 0.0087414 * t.powi(2) + t.powi(3) / 69699.0
+            - t.powi(4) / 14712000.0;
+    let latitude_argument: f64 = 93.2720950 + 483202.0175233 * t - 0.0036539 * t.powi(2);
+    let eccentricity: f64 = 1.0 - 0.002516 * t - 0.0000074 * t.powi(2);
+
+    let mut sum: f64 = 0.0;
+    for (d, m, m_prime, f, coefficient) in MOON_LONGITUDE_TERMS {
+        let argument: f64 = d as f64 * elongation
+            + m as f64 * sun_anomaly
+            + m_prime as f64 * moon_anomaly
+            + f as f64 * latitude_argument;
+        let correction: f64 = eccentricity.powi(m.unsigned_abs() as i32);
+        sum += coefficient as f64 * correction * argument.to_radians().sin();
+    }
+
+    let a1: f64 = 119.75 + 131.849 * t;
+    let a2: f64 = 53.09 + 479264.290 * t;
+    sum += 3958.0 * a1.to_radians().sin()
+        + 1962.0 * (mean_longitude - latitude_argument).to_radians().sin()
+        + 318.0 * a2.to_radians().sin();
+
+    normalize(mean_longitude + sum / 1_000_000.0 + nutation(t))
+}
+
+/// The moon's elongation from the sun in longitude, in degrees from 0 at new
+/// moon to 180 at full moon.
+pub(crate) fn lunar_phase(julian_day: f64) -> f64 {
+    normalize(moon_longitude(julian_day) - sun_longitude(julian_day))
+}
+
+/// The general precession in longitude since J2000, in degrees.
+pub(crate) fn precession(julian_day: f64) -> f64 {
+    let t: f64 = centuries(julian_day);
+    1.396971 * t + 0.0003086 * t.powi(2)
+}
+
+/// The instant nearest to `estimate` at which an angle that advances by
+/// about `rate` degrees a day reaches `target` degrees.
+pub(crate) fn angle_time(angle: impl Fn(f64) -> f64, target: f64, rate: f64, estimate: f64) -> f64 {
+    let mut julian_day: f64 = estimate;
+    for _ in 0..20 {
+        let error: f64 = normalize_signed(angle(julian_day) - target);
+        julian_day -= error / rate;
+        if error.abs() < 1e-6 {
+            break;
+        }
+    }
+    julian_day
+}
+
+/// The instant nearest to `estimate` at which the lunar phase is `phase`
+/// degrees.
+pub(crate) fn phase_time(phase: f64, estimate: f64) -> f64 {
+    angle_time(lunar_phase, phase, 360.0 / SYNODIC_MONTH, estimate)
+}
+
+/// The last new moon at or before an instant.
+pub(crate) fn new_moon_before(julian_day: f64) -> f64 {
+    let lunations: f64 = ((julian_day - NEW_MOON_2000) / SYNODIC_MONTH).floor();
+    let mut new_moon: f64 = phase_time(0.0, NEW_MOON_2000 + lunations * SYNODIC_MONTH);
+    while new_moon > julian_day {
+        new_moon = phase_time(0.0, new_moon - SYNODIC_MONTH);
+    }
+    loop {
+        let next: f64 = phase_time(0.0, new_moon + SYNODIC_MONTH);
+        if next > julian_day {
+            return new_moon;
+        }
+        new_moon = next;
+    }
+}
+
+/// The sun's right ascension and declination, in degrees.
+fn sun_equatorial(julian_day: f64) -> (f64, f64) {
+    let t: f64 = centuries(julian_day);
+    let obliquity: f64 = (23.439291 - 0.0130042 * t).to_radians();
+    let longitude: f64 = sun_longitude(julian_day).to_radians();
+    let right_ascension: f64 = (obliquity.cos() * longitude.sin()).atan2(longitude.cos());
+    let declination: f64 = (obliquity.sin() * longitude.sin()).asin();
+    (right_ascension.to_degrees(), declination.to_degrees())
+}
+
+/// Greenwich mean sidereal time, in degrees.
+fn sidereal_time(julian_day: f64) -> f64 {
+    normalize(280.46061837 + 360.98564736629 * (julian_day - J2000))
+}
+
+/// The sun's local hour angle and declination at a longitude, in degrees.
+fn sun_hour_angle(julian_day: f64, longitude: f64) -> (f64, f64) {
+    let (right_ascension, declination) = sun_equatorial(julian_day);
+    let hour_angle: f64 = normalize_signed(sidereal_time(julian_day) + longitude - right_ascension);
+    (hour_angle, declination)
+}
+
+/// The instant nearest to `estimate` at which the sun crosses the meridian
+/// of a longitude.
+pub(crate) fn solar_transit(estimate: f64, longitude: f64) -> f64 {
+    let mut julian_day: f64 = estimate;
+    for _ in 0..4 {
+        let (hour_angle, _) = sun_hour_angle(julian_day, longitude);
+        julian_day -= hour_angle / 360.0;
+    }
+    julian_day
+}
+
+/// The instant nearest to `estimate` at which the sun's centre is at
+/// `altitude` degrees, rising (`true`) or setting (`false`), or `None` if the
+/// sun stays above or below that altitude all day.
+pub(crate) fn sun_altitude_time(
+    estimate: f64,
+    latitude: f64,
+    longitude: f64,
+    altitude: f64,
+    rising: bool,
+) -> Option<f64> {
+    let latitude: f64 = latitude.to_radians();
+    let mut julian_day: f64 = estimate;
+    for _ in 0..6 {
+        let (hour_angle, declination) = sun_hour_angle(julian_day, longitude);
+        let declination: f64 = declination.to_radians();
+        let cos_target: f64 = (altitude.to_radians().sin() - latitude.sin() * declination.sin())
+            / (latitude.cos() * declination.cos());
+        if !(-1.0..=1.0).contains(&cos_target) {
+            return None;
+        }
+        let target: f64 = cos_target.acos().to_degrees();
+        let target: f64 = if rising { -target } else { target };
+        julian_day += normalize_signed(target - hour_angle) / 360.0;
+    }
+    Some(julian_day)
+}
+
+/// The altitude of the sun's centre at sunrise and sunset seen from an
+/// elevation in metres, lowered by the dip of the horizon.
+pub(crate) fn sunrise_altitude(elevation: f64) -> f64 {
+    SUNRISE_ALTITUDE - 0.0347 * elevation.max(0.0).sqrt()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn instant(year: i32, month: u32, day: u32, hour: u32, minute: u32) -> f64 {
+        julian_day_at(
+            NaiveDate::from_ymd_opt(year, month, day).unwrap(),
+            NaiveTime::from_hms_opt(hour, minute, 0).unwrap(),
+        )
+    }
+
+    #[test]
+    fn test_sun_longitude() {
+        // Meeus, example 25.a: 13 October 1992, 0h TD.
+        let longitude = sun_longitude(2448908.5 - delta_t(2448908.5));
+        assert!((longitude - 199.90895).abs() < 0.01, "{longitude}");
+    }
+
+    #[test]
+    fn test_moon_longitude() {
+        // Meeus, example 47.a: 12 April 1992, 0h TD, apparent longitude.
+        let longitude = moon_longitude(2448724.5 - delta_t(2448724.5));
+        assert!((longitude - 133.167265).abs() < 0.01, "{longitude}");
+    }
+
+    #[test]
+    fn test_new_moon() {
+        // The new moon of 29 March 2025 at 10:58 UTC.
+        let new_moon = new_moon_before(instant(2025, 4, 1, 0, 0));
+        assert!((new_moon - instant(2025, 3, 29, 10, 58)).abs() < 3.0 / 1440.0);
+    }
+
+    #[test]
+    fn test_sunrise() {
+        // Sunrise in Amritsar on 14 April 2025 at 06:03 IST.
+        let sunrise = sun_altitude_time(
+            instant(2025, 4, 14, 0, 30),
+            31.62,
+            74.877,
+            SUNRISE_ALTITUDE,
+            true,
+        )
+        .unwrap();
+        assert!((sunrise - instant(2025, 4, 14, 0, 33)).abs() < 2.0 / 1440.0);
+    }
+}
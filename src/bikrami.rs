@@ -0,0 +1,274 @@
+//! The Bikrami solar calendar, in which Punjab kept its dates before 2003.
+//!
+//! Each month begins on the day of the sidereal sankranti in India Standard
+//! Time, as in [`CalendarVariant::Bikrami`], so months last between 29 and 32
+//! days and drift against the Gregorian calendar by about a day every 70
+//! years. Years are counted in the Bikrami Samvat, which runs 1525 years
+//! ahead of the Nanakshahi year of the same solar month. Measured against the
+//! original Nanakshahi calendar, whose year is fixed to 14 March, a date is
+//! 1525 years ahead for most of the year and 1526 years ahead in the days
+//! between the Meen sankranti and 14 March, which came as much as a week
+//! apart in the Guru period.
+//!
+//! Months are measured under the Lahiri ayanamsa. Hukamnamas and rehitnamas
+//! may have been dated by jantris whose sankranti fell on a neighbouring
+//! day, so dates near the start of a month can differ by one.
+
+use std::fmt;
+
+use chrono::{Datelike, NaiveDate};
+
+use crate::lunar;
+use crate::{
+    CalendarVariant, NanakshahiDate, NanakshahiError, NanakshahiMonth, Script, VariantDate,
+};
+
+/// Years between the Nanakshahi year and the Bikrami Samvat.
+const SAMVAT_OFFSET: i32 = crate::EPOCH_ON_OR_AFTER_MID_MARCH + crate::SAMVAT_OFFSET;
+
+/// A date in the Bikrami solar calendar.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct BikramiDate {
+    pub samvat: i32,
+    pub month: NanakshahiMonth,
+    pub day: u8,
+}
+
+impl BikramiDate {
+    /// Create a Bikrami date.
+    ///
+    /// # Errors
+    /// Returns [`NanakshahiError::InvalidDay`] if the month has no such day
+    /// in the given Samvat, or [`NanakshahiError::YearOutOfRange`] if the
+    /// Samvat cannot be converted.
+    pub fn new(samvat: i32, month: NanakshahiMonth, day: u8) -> Result<Self, NanakshahiError> {
+        if day == 0 || day > days_in_month(samvat, month.number())? {
+            return Err(NanakshahiError::InvalidDay {
+                year: samvat,
+                month: month.number(),
+                day,
+            });
+        }
+        Ok(BikramiDate { samvat, month, day })
+    }
+
+    /// The Bikrami date of a date in the original Nanakshahi calendar.
+    ///
+    /// # Errors
+    /// Returns [`NanakshahiError::YearOutOfRange`] if the date is outside the
+    /// range supported by chrono.
+    ///
+    /// # Examples
+    /// ```
+    /// use nanakshahi::bikrami::BikramiDate;
+    /// use nanakshahi::{NanakshahiDate, NanakshahiMonth};
+    ///
+    /// // 1 Vaisakh 557 fell on 14 April 2025, the first of the solar month.
+    /// let vaisakhi = NanakshahiDate::new(557, NanakshahiMonth::Vaisakh, 1)?;
+    /// let date = BikramiDate::from_nanakshahi(vaisakhi)?;
+    /// assert_eq!(date.to_string(), "Vaisakh 1, 2082");
+    /// # Ok::<(), nanakshahi::NanakshahiError>(())
+    /// ```
+    pub fn from_nanakshahi(date: NanakshahiDate) -> Result<Self, NanakshahiError> {
+        let gregorian: NaiveDate = date.to_gregorian()?;
+        to(
+            gregorian.year(),
+            gregorian.month() as u8,
+            gregorian.day() as u8,
+        )
+    }
+
+    /// The date in the original Nanakshahi calendar.
+    ///
+    /// # Errors
+    /// Returns [`NanakshahiError::YearOutOfRange`] if the date is outside the
+    /// range supported by chrono.
+    pub fn to_nanakshahi(&self) -> Result<NanakshahiDate, NanakshahiError> {
+        let gregorian: NaiveDate = self.to_gregorian()?;
+        crate::to(
+            gregorian.year(),
+            gregorian.month() as u8,
+            gregorian.day() as u8,
+        )
+    }
+
+    /// The Gregorian date.
+    ///
+    /// # Errors
+    /// See [`from`].
+    pub fn to_gregorian(&self) -> Result<NaiveDate, NanakshahiError> {
+        from(self.samvat, self.month.number(), self.day)
+    }
+
+    /// The date written out in the given script, such as "Vaisakh 1, 2082".
+    pub fn to_string_in(&self, script: Script) -> String {
+        format!(
+            "{} {}, {}",
+            self.month.name_in(script),
+            script.numeral(self.day as u64),
+            lunar::numeral(self.samvat, script)
+        )
+    }
+}
+
+impl fmt::Display for BikramiDate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.to_string_in(Script::Latin))
+    }
+}
+
+/// Convert a Gregorian date to a Bikrami solar date.
+///
+/// # Errors
+/// Returns [`NanakshahiError::InvalidGregorianDate`] if the Gregorian date
+/// does not exist or is outside the range supported by chrono.
+///
+/// # Examples
+/// ```
+/// use chrono::Datelike;
+/// use nanakshahi::{bikrami, julian, NanakshahiMonth};
+///
+/// // The Khalsa was founded on 1 Vaisakh 1756, 30 March 1699 in the Julian
+/// // calendar.
+/// let gregorian = julian::to_gregorian(1699, 3, 30)?;
+/// let date = bikrami::to(gregorian.year(), gregorian.month() as u8, gregorian.day() as u8)?;
+/// assert_eq!((date.samvat, date.month, date.day), (1756, NanakshahiMonth::Vaisakh, 1));
+/// # Ok::<(), nanakshahi::NanakshahiError>(())
+/// ```
+pub fn to(year: i32, month: u8, day: u8) -> Result<BikramiDate, NanakshahiError> {
+    let date: VariantDate = CalendarVariant::Bikrami.to(year, month, day)?;
+    Ok(BikramiDate {
+        samvat: samvat(date.year())?,
+        month: date.month(),
+        day: date.day(),
+    })
+}
+
+/// Convert a Bikrami solar date to a Gregorian date.
+///
+/// # Errors
+/// Returns [`NanakshahiError::InvalidMonth`] or
+/// [`NanakshahiError::InvalidDay`] if the date does not exist, or
+/// [`NanakshahiError::YearOutOfRange`] if the Samvat cannot be converted.
+///
+/// # Examples
+/// ```
+/// use chrono::NaiveDate;
+/// use nanakshahi::bikrami;
+///
+/// let date = bikrami::from(2082, 2, 1)?;
+/// assert_eq!(date, NaiveDate::from_ymd_opt(2025, 4, 14).unwrap());
+/// # Ok::<(), nanakshahi::NanakshahiError>(())
+/// ```
+pub fn from(samvat: i32, month: u8, day: u8) -> Result<NaiveDate, NanakshahiError> {
+    CalendarVariant::Bikrami
+        .from(nanakshahi_year(samvat)?, month, day)
+        .map_err(|error| with_samvat(error, samvat))
+}
+
+/// The number of days in a month of a Bikrami Samvat.
+///
+/// # Errors
+/// Returns [`NanakshahiError::InvalidMonth`] if the month is not between 1
+/// and 12, or [`NanakshahiError::YearOutOfRange`] if the Samvat cannot be
+/// converted.
+pub fn days_in_month(samvat: i32, month: u8) -> Result<u8, NanakshahiError> {
+    CalendarVariant::Bikrami
+        .days_in_month(nanakshahi_year(samvat)?, month)
+        .map_err(|error| with_samvat(error, samvat))
+}
+
+fn samvat(year: i32) -> Result<i32, NanakshahiError> {
+    year.checked_add(SAMVAT_OFFSET)
+        .ok_or(NanakshahiError::YearOutOfRange(year))
+}
+
+fn nanakshahi_year(samvat: i32) -> Result<i32, NanakshahiError> {
+    samvat
+        .checked_sub(SAMVAT_OFFSET)
+        .ok_or(NanakshahiError::YearOutOfRange(samvat))
+}
+
+/// Report errors against the Samvat rather than the Nanakshahi year.
+fn with_samvat(error: NanakshahiError, samvat: i32) -> NanakshahiError {
+    match error {
+        NanakshahiError::InvalidDay { month, day, .. } => NanakshahiError::InvalidDay {
+            year: samvat,
+            month,
+            day,
+        },
+        NanakshahiError::YearOutOfRange(_) => NanakshahiError::YearOutOfRange(samvat),
+        error => error,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn naive(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn test_to_and_from() {
+        // Maghi on 14 January 2025.
+        let maghi = to(2025, 1, 14).unwrap();
+        assert_eq!(
+            maghi,
+            BikramiDate::new(2081, NanakshahiMonth::Magh, 1).unwrap()
+        );
+        assert_eq!(maghi.to_gregorian(), Ok(naive(2025, 1, 14)));
+
+        let mut date = naive(1699, 1, 1);
+        while date < naive(1700, 1, 1) {
+            let bikrami = to(date.year(), date.month() as u8, date.day() as u8).unwrap();
+            assert_eq!(bikrami.to_gregorian(), Ok(date));
+            date = date.succ_opt().unwrap();
+        }
+    }
+
+    #[test]
+    fn test_samvat_offset() {
+        // The sidereal Chet began before 14 March in the Guru period.
+        let chet = from(1756, 1, 1).unwrap();
+        assert!(chet < naive(1699, 3, 14), "{chet}");
+        let original = crate::to(chet.year(), chet.month() as u8, chet.day() as u8).unwrap();
+        assert_eq!(original.year + 1526, 1756);
+
+        let date = BikramiDate::new(1756, NanakshahiMonth::Jeth, 10).unwrap();
+        assert_eq!(date.to_nanakshahi().unwrap().year + 1525, 1756);
+        assert_eq!(
+            BikramiDate::from_nanakshahi(date.to_nanakshahi().unwrap()),
+            Ok(date)
+        );
+    }
+
+    #[test]
+    fn test_invalid_dates() {
+        assert_eq!(days_in_month(2082, 5), Ok(32));
+        assert_eq!(
+            from(2082, 6, 32),
+            Err(NanakshahiError::InvalidDay {
+                year: 2082,
+                month: 6,
+                day: 32
+            })
+        );
+        assert_eq!(from(2082, 13, 1), Err(NanakshahiError::InvalidMonth(13)));
+        assert_eq!(
+            BikramiDate::new(2082, NanakshahiMonth::Chet, 0),
+            Err(NanakshahiError::InvalidDay {
+                year: 2082,
+                month: 1,
+                day: 0
+            })
+        );
+        assert_eq!(
+            BikramiDate::new(2082, NanakshahiMonth::Sawan, 32)
+                .unwrap()
+                .to_string_in(Script::Gurmukhi),
+            "ਸਾਵਣ ੩੨, ੨੦੮੨"
+        );
+    }
+}
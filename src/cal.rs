@@ -0,0 +1,435 @@
+//! Month and year grids for the terminal, in the manner of `cal`.
+//!
+//! Each week runs from Aitvar to Shanivar. Under every row of Nanakshahi
+//! days is a row with the matching Gregorian days, dimmed when colour is
+//! enabled. Days with an observance are marked with `*`, and today is shown
+//! in reverse video.
+//!
+//! Columns are aligned by display width rather than by `char` count, so that
+//! the vowel signs and other combining marks of Gurmukhi, Shahmukhi and
+//! Devanagari text take no column of their own.
+
+use chrono::{Datelike, Duration, NaiveDate};
+
+use crate::format::short_weekday_name;
+use crate::observances;
+use crate::{
+    CalendarVariant, Location, NanakshahiError, NanakshahiMonth, NanakshahiWeekday, Script,
+};
+
+/// The width of a day, in columns.
+const CELL: usize = 4;
+/// The width of a month, in columns.
+const WIDTH: usize = 7 * CELL;
+/// The space between months in a year grid.
+const GUTTER: &str = "  ";
+/// The number of months in each row of a year grid.
+const MONTHS_PER_ROW: usize = 3;
+
+const REVERSE: &str = "\x1b[7m";
+const DIM: &str = "\x1b[2m";
+const RESET: &str = "\x1b[0m";
+
+/// What to show in a grid, and how.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CalOptions {
+    /// The calendar variant by which months are laid out.
+    pub variant: CalendarVariant,
+    /// The script of month names, weekday names and Nanakshahi days.
+    pub script: Script,
+    /// Show the Gregorian date under each day.
+    pub gregorian: bool,
+    /// Mark the days of observances, and list them under a month grid.
+    pub observances: bool,
+    /// The place by which lunar observances are dated.
+    pub location: Location,
+    /// The day to highlight, if any.
+    pub today: Option<NaiveDate>,
+    /// Use ANSI escape sequences for today and the Gregorian dates.
+    pub color: bool,
+}
+
+impl Default for CalOptions {
+    /// The original calendar in Latin script, with Gregorian dates and
+    /// observances reckoned at Amritsar, without colour.
+    fn default() -> Self {
+        CalOptions {
+            variant: CalendarVariant::default(),
+            script: Script::default(),
+            gregorian: true,
+            observances: true,
+            location: Location::AMRITSAR,
+            today: None,
+            color: false,
+        }
+    }
+}
+
+/// An observance shown on a grid.
+struct Mark {
+    date: NaiveDate,
+    name: &'static str,
+}
+
+/// The grid of a month, followed by the observances in it.
+///
+/// # Errors
+/// Returns [`NanakshahiError::YearOutOfRange`] if the year cannot be
+/// converted.
+///
+/// # Examples
+/// ```
+/// use nanakshahi::cal::{self, CalOptions};
+/// use nanakshahi::NanakshahiMonth;
+///
+/// let grid = cal::month_grid(557, NanakshahiMonth::Poh, &CalOptions::default())?;
+/// let lines: Vec<&str> = grid.lines().collect();
+///
+/// assert_eq!(lines[0], "          Poh 557");
+/// assert_eq!(lines[1], "    Dec 2025 – Jan 2026");
+/// assert_eq!(lines[2], "Ait Som Man Bud Vee Shu Sha");
+/// assert_eq!(lines[3], "  1   2   3   4   5   6   7");
+/// assert_eq!(lines[4], " 14  15  16  17  18  19  20");
+/// assert_eq!(lines[5], "  8*  9  10  11  12  13* 14");
+/// # Ok::<(), nanakshahi::NanakshahiError>(())
+/// ```
+pub fn month_grid(
+    year: i32,
+    month: NanakshahiMonth,
+    options: &CalOptions,
+) -> Result<String, NanakshahiError> {
+    let marks: Vec<Mark> = marks(year, options)?;
+    let mut lines: Vec<String> = month_lines(year, month, options, &marks, true)?;
+    while lines.last().is_some_and(|line| line.trim().is_empty()) {
+        lines.pop();
+    }
+
+    let first: NaiveDate = options.variant.from(year, month.number(), 1)?;
+    let days: u8 = options.variant.days_in_month(year, month.number())?;
+    let in_month: Vec<&Mark> = marks
+        .iter()
+        .filter(|mark| mark.date >= first && (mark.date - first).num_days() < days as i64)
+        .collect();
+    if !in_month.is_empty() {
+        lines.push(String::new());
+    }
+    for mark in in_month {
+        let day: u64 = (mark.date - first).num_days() as u64 + 1;
+        let numeral: String = options.script.numeral(day);
+        lines.push(format!("{} {}", pad_left(&numeral, 3), mark.name));
+    }
+    Ok(join(&lines))
+}
+
+/// The grids of the twelve months of a year, three to a row.
+///
+/// # Errors
+/// Returns [`NanakshahiError::YearOutOfRange`] if the year cannot be
+/// converted.
+pub fn year_grid(year: i32, options: &CalOptions) -> Result<String, NanakshahiError> {
+    let marks: Vec<Mark> = marks(year, options)?;
+    let width: usize = MONTHS_PER_ROW * WIDTH + (MONTHS_PER_ROW - 1) * GUTTER.len();
+    let mut lines: Vec<String> = vec![center(&year_numeral(year, options.script), width)];
+
+    for row in NanakshahiMonth::ALL.chunks(MONTHS_PER_ROW) {
+        let grids: Vec<Vec<String>> = row
+            .iter()
+            .map(|&month| month_lines(year, month, options, &marks, false))
+            .collect::<Result<_, _>>()?;
+        lines.push(String::new());
+        for index in 0..grids[0].len() {
+            let parts: Vec<&str> = grids.iter().map(|grid| grid[index].as_str()).collect();
+            lines.push(parts.join(GUTTER));
+        }
+    }
+    Ok(join(&lines))
+}
+
+/// The observances of a year under the options, in date order.
+fn marks(year: i32, options: &CalOptions) -> Result<Vec<Mark>, NanakshahiError> {
+    let mut marks: Vec<Mark> = Vec::new();
+    if !options.observances {
+        return Ok(marks);
+    }
+
+    let location: &Location = &options.location;
+    for occurrence in observances::observances_in_year_with(year, options.variant, location)? {
+        marks.push(Mark {
+            date: occurrence.gregorian,
+            name: occurrence.observance.name_in(options.script),
+        });
+    }
+    for occurrence in observances::lunar_observances_in_year(year, location)? {
+        marks.push(Mark {
+            date: occurrence.gregorian,
+            name: occurrence.observance.name_in(options.script),
+        });
+    }
+    marks.sort_by_key(|mark| mark.date);
+    Ok(marks)
+}
+
+/// The lines of a month grid, each [`WIDTH`] columns wide and with six weeks
+/// so that grids line up side by side.
+fn month_lines(
+    year: i32,
+    month: NanakshahiMonth,
+    options: &CalOptions,
+    marks: &[Mark],
+    with_year: bool,
+) -> Result<Vec<String>, NanakshahiError> {
+    let script: Script = options.script;
+    let days: u8 = options.variant.days_in_month(year, month.number())?;
+    let first: NaiveDate = options.variant.from(year, month.number(), 1)?;
+    let last: NaiveDate = first + Duration::days(days as i64 - 1);
+
+    let mut lines: Vec<String> = Vec::new();
+    let title: String = if with_year {
+        format!("{} {}", month.name_in(script), year_numeral(year, script))
+    } else {
+        month.name_in(script).to_string()
+    };
+    lines.push(center(&title, WIDTH));
+    if options.gregorian {
+        let span: String = if first.year() == last.year() {
+            format!("{} – {}", first.format("%b"), last.format("%b %Y"))
+        } else {
+            format!("{} – {}", first.format("%b %Y"), last.format("%b %Y"))
+        };
+        lines.push(paint(&center(&span, WIDTH), DIM, options.color));
+    }
+    lines.push(
+        NanakshahiWeekday::ALL
+            .iter()
+            .map(|&weekday| {
+                let name: &str = truncate(short_weekday_name(weekday, script), CELL - 1);
+                format!("{} ", pad_left(name, CELL - 1))
+            })
+            .collect(),
+    );
+
+    let offset: i64 = first.weekday().num_days_from_sunday() as i64;
+    for week in 0..6 {
+        let mut row: String = String::new();
+        let mut gregorian_row: String = String::new();
+        for weekday in 0..7 {
+            let day: i64 = week * 7 + weekday - offset + 1;
+            if day < 1 || day > days as i64 {
+                row.push_str(&" ".repeat(CELL));
+                gregorian_row.push_str(&" ".repeat(CELL));
+                continue;
+            }
+
+            let date: NaiveDate = first + Duration::days(day - 1);
+            let numeral: String = pad_left(&script.numeral(day as u64), CELL - 1);
+            let today: bool = options.today == Some(date);
+            row.push_str(&paint(&numeral, REVERSE, today && options.color));
+            row.push(if marks.iter().any(|mark| mark.date == date) {
+                '*'
+            } else {
+                ' '
+            });
+            gregorian_row.push_str(&pad_left(&date.day().to_string(), CELL - 1));
+            gregorian_row.push(' ');
+        }
+        lines.push(row);
+        if options.gregorian {
+            lines.push(paint(&gregorian_row, DIM, options.color));
+        }
+    }
+    Ok(lines)
+}
+
+/// A year in a script, with a minus sign before the era.
+fn year_numeral(year: i32, script: Script) -> String {
+    let numeral: String = script.numeral(year.unsigned_abs() as u64);
+    if year < 0 {
+        format!("-{numeral}")
+    } else {
+        numeral
+    }
+}
+
+/// Lines without their trailing spaces, and without trailing blank lines.
+fn join(lines: &[String]) -> String {
+    lines
+        .iter()
+        .map(|line| line.trim_end())
+        .collect::<Vec<&str>>()
+        .join("\n")
+        .trim_end()
+        .to_string()
+}
+
+/// Wrap text in an ANSI style, leaving the spaces around it outside.
+fn paint(text: &str, style: &str, color: bool) -> String {
+    let trimmed: &str = text.trim();
+    if !color || trimmed.is_empty() {
+        return text.to_string();
+    }
+    let start: usize = text.len() - text.trim_start().len();
+    format!(
+        "{}{style}{trimmed}{RESET}{}",
+        &text[..start],
+        &text[start + trimmed.len()..]
+    )
+}
+
+fn pad_left(text: &str, width: usize) -> String {
+    let padding: usize = width.saturating_sub(display_width(text));
+    format!("{}{text}", " ".repeat(padding))
+}
+
+fn center(text: &str, width: usize) -> String {
+    let padding: usize = width.saturating_sub(display_width(text));
+    let left: usize = padding / 2;
+    format!("{}{text}{}", " ".repeat(left), " ".repeat(padding - left))
+}
+
+/// The longest start of a text that fits in a number of columns, without
+/// splitting a letter from its marks.
+fn truncate(text: &str, width: usize) -> &str {
+    let mut used: usize = 0;
+    let mut end: usize = 0;
+    let mut chars = text.char_indices().peekable();
+    while let Some((_, c)) = chars.next() {
+        let mut cluster: usize = char_width(c);
+        let mut cluster_end: usize = end + c.len_utf8();
+        while let Some(&(index, mark)) = chars.peek() {
+            if !is_mark(mark) {
+                break;
+            }
+            cluster += char_width(mark);
+            cluster_end = index + mark.len_utf8();
+            chars.next();
+        }
+        if used + cluster > width {
+            break;
+        }
+        used += cluster;
+        end = cluster_end;
+    }
+    &text[..end]
+}
+
+/// The number of terminal columns a text takes.
+fn display_width(text: &str) -> usize {
+    text.chars().map(char_width).sum()
+}
+
+/// The columns taken by a character, as by `wcwidth`: none for nonspacing
+/// marks and zero-width characters, and one for everything else used here,
+/// including spacing vowel signs such as Gurmukhi kanna.
+fn char_width(c: char) -> usize {
+    if is_nonspacing(c) {
+        0
+    } else {
+        1
+    }
+}
+
+/// Whether a character combines with the letter before it.
+fn is_mark(c: char) -> bool {
+    is_nonspacing(c)
+        || matches!(
+            c,
+            // Gurmukhi visarga and the spacing vowel signs
+            '\u{0A03}' | '\u{0A3E}'..='\u{0A40}'
+            // Devanagari visarga and the spacing vowel signs
+            | '\u{0903}' | '\u{093B}' | '\u{093E}'..='\u{0940}' | '\u{0949}'..='\u{094C}'
+            | '\u{094E}'..='\u{094F}'
+        )
+}
+
+fn is_nonspacing(c: char) -> bool {
+    matches!(
+        c,
+        // Combining diacritical marks
+        '\u{0300}'..='\u{036F}'
+        // Arabic harakat and Quranic marks
+        | '\u{0610}'..='\u{061A}' | '\u{064B}'..='\u{065F}' | '\u{0670}'
+        | '\u{06D6}'..='\u{06DC}' | '\u{06DF}'..='\u{06E4}' | '\u{06E7}'..='\u{06E8}'
+        | '\u{06EA}'..='\u{06ED}'
+        // Devanagari
+        | '\u{0900}'..='\u{0902}' | '\u{093A}' | '\u{093C}' | '\u{0941}'..='\u{0948}'
+        | '\u{094D}' | '\u{0951}'..='\u{0957}' | '\u{0962}'..='\u{0963}'
+        // Gurmukhi adhak, bindi, tippi, nukta, vowel signs and virama
+        | '\u{0A01}'..='\u{0A02}' | '\u{0A3C}' | '\u{0A41}'..='\u{0A42}'
+        | '\u{0A47}'..='\u{0A48}' | '\u{0A4B}'..='\u{0A4D}' | '\u{0A51}'
+        | '\u{0A70}'..='\u{0A71}' | '\u{0A75}'
+        // Zero-width spaces, joiners and direction marks
+        | '\u{200B}'..='\u{200F}'
+        // Variation selectors
+        | '\u{FE00}'..='\u{FE0F}'
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display_width() {
+        assert_eq!(display_width("Poh"), 3);
+        assert_eq!(display_width("ਪੋਹ"), 2);
+        assert_eq!(display_width("ਸ਼ੁੱਕਰ"), 3);
+        assert_eq!(display_width("ਸ਼ਨੀ"), 3);
+        assert_eq!(truncate("Shukar", 3), "Shu");
+        assert_eq!(truncate("ਮੰਗਲ", 2), "ਮੰਗ");
+        assert_eq!(truncate("ਸ਼ਨੀ", 2), "ਸ਼");
+    }
+
+    #[test]
+    fn test_month_grid() {
+        let options = CalOptions {
+            today: NaiveDate::from_ymd_opt(2025, 12, 27),
+            color: true,
+            ..CalOptions::default()
+        };
+        let grid = month_grid(557, NanakshahiMonth::Poh, &options).unwrap();
+        let lines: Vec<&str> = grid.lines().collect();
+
+        assert_eq!(lines[1], format!("    {DIM}Dec 2025 – Jan 2026{RESET}"));
+        assert_eq!(
+            lines[5],
+            format!("  8*  9  10  11  12  13* {REVERSE}14{RESET}")
+        );
+        assert_eq!(lines[6], format!(" {DIM}21  22  23  24  25  26  27{RESET}"));
+        assert!(grid.ends_with("\n 23 Prakash Guru Gobind Singh Ji"));
+        assert!(lines.iter().all(|line| !line.ends_with(' ')));
+    }
+
+    #[test]
+    fn test_gurmukhi_alignment() {
+        let options = CalOptions {
+            script: Script::Gurmukhi,
+            gregorian: false,
+            observances: false,
+            ..CalOptions::default()
+        };
+        let grid = month_grid(557, NanakshahiMonth::Poh, &options).unwrap();
+        let lines: Vec<&str> = grid.lines().collect();
+
+        assert_eq!(lines[0], "           ਪੋਹ ੫੫੭");
+        assert_eq!(lines[1], " ਐਤ  ਸੋਮ ਮੰਗਲ  ਬੁੱਧ ਵੀਰ ਸ਼ੁੱਕਰ ਸ਼ਨੀ");
+        for line in &lines[1..] {
+            assert!(display_width(line) <= WIDTH, "{line}");
+        }
+    }
+
+    #[test]
+    fn test_year_grid() {
+        let options = CalOptions {
+            variant: CalendarVariant::Bikrami,
+            observances: false,
+            ..CalOptions::default()
+        };
+        let grid = year_grid(557, &options).unwrap();
+        let lines: Vec<&str> = grid.lines().collect();
+
+        assert_eq!(lines[0].trim(), "557");
+        // Sawan had 32 days in 557 under the Bikrami calendar.
+        assert!(grid.contains(" 32"));
+        assert!(lines[2].starts_with("            Chet"));
+    }
+}
@@ -0,0 +1,695 @@
+use std::fmt;
+use std::str::FromStr;
+
+use chrono::{DateTime, Datelike, Duration, NaiveDate, TimeZone, Utc};
+
+use crate::format::DelayedFormat;
+use crate::lunar::{self, Reckoning};
+use crate::parse;
+use crate::{
+    astro, Location, NanakshahiError, NanakshahiMonth, NanakshahiWeekday, ParseError, Script,
+    EPOCH_ON_OR_AFTER_MID_MARCH, JULIAN_DAY_OF_COMMON_ERA, NANAKSHAHI_DAYS_IN_MONTHS,
+    RATA_DIE_OF_EPOCH,
+};
+
+/// Days in a common year before the start of each month, from
+/// [`NANAKSHAHI_DAYS_IN_MONTHS`].
+const DAYS_BEFORE_MONTHS: [i32; 12] = days_before_months();
+
+const fn days_before_months() -> [i32; 12] {
+    let mut days: [i32; 12] = [0; 12];
+    let mut index: usize = 1;
+    while index < 12 {
+        days[index] = days[index - 1] + NANAKSHAHI_DAYS_IN_MONTHS[index - 1];
+        index += 1;
+    }
+    days
+}
+
+/// When one civil day ends and the next begins.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum DayBoundary {
+    /// Local midnight, as in the civil calendar.
+    #[default]
+    Midnight,
+    /// Sunrise at a location, by traditional reckoning. Where the sun does
+    /// not rise, the day begins at 06:00 in the location's time zone.
+    Sunrise(Location),
+}
+
+/// A date in the Nanakshahi calendar.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct NanakshahiDate {
+    /// The year, numbered astronomically so that the year before 0 is -1.
+    pub year: i32,
+    pub month: NanakshahiMonth,
+    pub day: u8,
+}
+
+impl NanakshahiDate {
+    /// Create a Nanakshahi date, checking that the day exists in the month.
+    ///
+    /// # Examples
+    /// ```
+    /// use nanakshahi::{NanakshahiDate, NanakshahiMonth};
+    ///
+    /// let date = NanakshahiDate::new(557, NanakshahiMonth::Chet, 1)?;
+    /// assert!(NanakshahiDate::new(556, NanakshahiMonth::Phaggan, 31).is_err());
+    /// # Ok::<(), nanakshahi::NanakshahiError>(())
+    /// ```
+    pub fn new(year: i32, month: NanakshahiMonth, day: u8) -> Result<Self, NanakshahiError> {
+        if day == 0 || day > month.days(year) {
+            return Err(NanakshahiError::InvalidDay {
+                year,
+                month: month.number(),
+                day,
+            });
+        }
+
+        Ok(NanakshahiDate { year, month, day })
+    }
+
+    /// The date of an instant in its time zone, with days beginning at
+    /// midnight.
+    ///
+    /// # Examples
+    /// ```
+    /// use chrono::TimeZone;
+    /// use chrono_tz::America::Toronto;
+    /// use nanakshahi::{NanakshahiDate, NanakshahiMonth};
+    ///
+    /// let instant = Toronto.with_ymd_and_hms(2025, 3, 13, 23, 0, 0).unwrap();
+    /// let date = NanakshahiDate::from_datetime(&instant);
+    /// assert_eq!(date, NanakshahiDate::new(556, NanakshahiMonth::Phaggan, 30)?);
+    ///
+    /// // It is already 14 March in India.
+    /// let date = NanakshahiDate::from_datetime(&instant.with_timezone(&chrono_tz::Asia::Kolkata));
+    /// assert_eq!(date, NanakshahiDate::new(557, NanakshahiMonth::Chet, 1)?);
+    /// # Ok::<(), nanakshahi::NanakshahiError>(())
+    /// ```
+    pub fn from_datetime<Tz: TimeZone>(datetime: &DateTime<Tz>) -> Self {
+        Self::from_datetime_with(datetime, DayBoundary::Midnight)
+    }
+
+    /// The date of an instant, with days beginning at the given boundary.
+    ///
+    /// Days that begin at midnight are dated in the instant's own time zone,
+    /// and days that begin at sunrise in the time zone of the location.
+    ///
+    /// # Examples
+    /// ```
+    /// use chrono::TimeZone;
+    /// use chrono_tz::Asia::Kolkata;
+    /// use nanakshahi::{DayBoundary, Location, NanakshahiDate, NanakshahiMonth};
+    ///
+    /// // Sunrise in Amritsar on 14 March 2025 came at 06:36.
+    /// let instant = Kolkata.with_ymd_and_hms(2025, 3, 14, 5, 0, 0).unwrap();
+    /// let sunrise = DayBoundary::Sunrise(Location::AMRITSAR);
+    /// let date = NanakshahiDate::from_datetime_with(&instant, sunrise);
+    /// assert_eq!(date, NanakshahiDate::new(556, NanakshahiMonth::Phaggan, 30)?);
+    /// # Ok::<(), nanakshahi::NanakshahiError>(())
+    /// ```
+    pub fn from_datetime_with<Tz: TimeZone>(
+        datetime: &DateTime<Tz>,
+        boundary: DayBoundary,
+    ) -> Self {
+        let date: NaiveDate = match boundary {
+            DayBoundary::Midnight => datetime.date_naive(),
+            DayBoundary::Sunrise(location) => {
+                let date: NaiveDate = datetime.with_timezone(&location.offset).date_naive();
+                let sunrise: f64 = lunar::day_boundary(date, &location, Reckoning::Sunrise);
+                if astro::julian_day(datetime.with_timezone(&Utc)) < sunrise {
+                    date.pred_opt().unwrap_or(date)
+                } else {
+                    date
+                }
+            }
+        };
+        NanakshahiDate::from(date)
+    }
+
+    /// Today's date in a time zone, with days beginning at midnight.
+    ///
+    /// # Examples
+    /// ```
+    /// use nanakshahi::NanakshahiDate;
+    ///
+    /// let vancouver = NanakshahiDate::today_in(&chrono_tz::America::Vancouver);
+    /// let amritsar = NanakshahiDate::today_in(&chrono_tz::Asia::Kolkata);
+    /// assert!(amritsar.signed_duration_since(vancouver).num_days() <= 1);
+    /// ```
+    pub fn today_in<Tz: TimeZone>(tz: &Tz) -> Self {
+        Self::today_in_with(tz, DayBoundary::Midnight)
+    }
+
+    /// Today's date in a time zone, with days beginning at the given
+    /// boundary.
+    pub fn today_in_with<Tz: TimeZone>(tz: &Tz, boundary: DayBoundary) -> Self {
+        Self::from_datetime_with(&Utc::now().with_timezone(tz), boundary)
+    }
+
+    /// Convert this date to a Gregorian date.
+    ///
+    /// # Examples
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use nanakshahi::{NanakshahiDate, NanakshahiMonth};
+    ///
+    /// let date = NanakshahiDate::new(557, NanakshahiMonth::Chet, 1)?;
+    /// assert_eq!(date.to_gregorian()?, NaiveDate::from_ymd_opt(2025, 3, 14).unwrap());
+    /// # Ok::<(), nanakshahi::NanakshahiError>(())
+    /// ```
+    pub fn to_gregorian(&self) -> Result<NaiveDate, NanakshahiError> {
+        crate::from(self.year, self.month.number(), self.day)
+    }
+
+    /// Write the date as day, month name and year in the given script.
+    ///
+    /// # Examples
+    /// ```
+    /// use nanakshahi::{NanakshahiDate, NanakshahiMonth, Script};
+    ///
+    /// let date = NanakshahiDate::new(557, NanakshahiMonth::Chet, 1)?;
+    /// assert_eq!(date.to_string_in(Script::Latin), "1 Chet 557");
+    /// assert_eq!(date.to_string_in(Script::Gurmukhi), "੧ ਚੇਤ ੫੫੭");
+    /// # Ok::<(), nanakshahi::NanakshahiError>(())
+    /// ```
+    pub fn to_string_in(&self, script: Script) -> String {
+        self.format_in("%-d %B %Y", script).to_string()
+    }
+
+    /// The year in the Nanakshahi era, or in the era before it.
+    ///
+    /// Returns `(true, year)` for year 1 and later, and `(false, 1 - year)`
+    /// for years in the Before Nanakshahi era, so that year 0 is 1 BNS.
+    ///
+    /// # Examples
+    /// ```
+    /// use nanakshahi::{NanakshahiDate, NanakshahiMonth};
+    ///
+    /// let date = NanakshahiDate::new(-4, NanakshahiMonth::Chet, 1)?;
+    /// assert_eq!(date.year_ns(), (false, 5));
+    /// # Ok::<(), nanakshahi::NanakshahiError>(())
+    /// ```
+    pub fn year_ns(&self) -> (bool, u32) {
+        year_ns(self.year)
+    }
+
+    /// Format the date with a `strftime`-style pattern in Latin script.
+    ///
+    /// See the [`format`](crate::format) module for the supported specifiers.
+    ///
+    /// # Examples
+    /// ```
+    /// use nanakshahi::{NanakshahiDate, NanakshahiMonth};
+    ///
+    /// let date = NanakshahiDate::new(557, NanakshahiMonth::Chet, 1)?;
+    /// assert_eq!(date.format("%-d %B %Y %E").to_string(), "1 Chet 557 NS");
+    /// # Ok::<(), nanakshahi::NanakshahiError>(())
+    /// ```
+    pub fn format<'a>(&self, pattern: &'a str) -> DelayedFormat<'a> {
+        self.format_in(pattern, Script::Latin)
+    }
+
+    /// Format the date with a `strftime`-style pattern in the given script.
+    ///
+    /// # Examples
+    /// ```
+    /// use nanakshahi::{NanakshahiDate, NanakshahiMonth, Script};
+    ///
+    /// let date = NanakshahiDate::new(557, NanakshahiMonth::Chet, 1)?;
+    /// assert_eq!(date.format_in("%B %-d, %Y", Script::Gurmukhi).to_string(), "ਚੇਤ ੧, ੫੫੭");
+    /// # Ok::<(), nanakshahi::NanakshahiError>(())
+    /// ```
+    pub fn format_in<'a>(&self, pattern: &'a str, script: Script) -> DelayedFormat<'a> {
+        DelayedFormat::new(*self, pattern, script)
+    }
+
+    /// Parse a date with a `strftime`-style pattern.
+    ///
+    /// Numbers may be written in the digits of any [`Script`], and month and
+    /// weekday names in any script or common romanized spelling, ignoring
+    /// case. Whitespace in the pattern matches any run of whitespace.
+    ///
+    /// # Examples
+    /// ```
+    /// use nanakshahi::{NanakshahiDate, NanakshahiMonth};
+    ///
+    /// let date = NanakshahiDate::parse_from_str("1 Baisakh 557", "%d %B %Y")?;
+    /// assert_eq!(date, NanakshahiDate::new(557, NanakshahiMonth::Vaisakh, 1)?);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn parse_from_str(s: &str, pattern: &str) -> Result<Self, ParseError> {
+        parse::parse(s, pattern)
+    }
+
+    /// The day of the year, from 1 on 1 Chet to 365 or 366 on the last day of
+    /// Phaggan.
+    pub fn ordinal(&self) -> u32 {
+        days_before_month(self.month) + self.day as u32
+    }
+
+    /// The day of the week.
+    ///
+    /// # Examples
+    /// ```
+    /// use nanakshahi::{NanakshahiDate, NanakshahiMonth, NanakshahiWeekday};
+    ///
+    /// let date = NanakshahiDate::new(557, NanakshahiMonth::Chet, 1)?;
+    /// assert_eq!(date.weekday(), NanakshahiWeekday::Shukarvar);
+    /// # Ok::<(), nanakshahi::NanakshahiError>(())
+    /// ```
+    pub fn weekday(&self) -> NanakshahiWeekday {
+        // 1 Chet 0 (14 March 1468) was a Shanivar.
+        let days: i64 = self.to_days() + NanakshahiWeekday::Shanivar as i64;
+        NanakshahiWeekday::ALL[days.rem_euclid(7) as usize]
+    }
+
+    /// Add a number of days, returning `None` if the result is out of range.
+    ///
+    /// # Examples
+    /// ```
+    /// use nanakshahi::{NanakshahiDate, NanakshahiMonth};
+    ///
+    /// let date = NanakshahiDate::new(557, NanakshahiMonth::Chet, 1)?;
+    /// let later = NanakshahiDate::new(557, NanakshahiMonth::Vaisakh, 10)?;
+    /// assert_eq!(date.checked_add_days(40), Some(later));
+    /// # Ok::<(), nanakshahi::NanakshahiError>(())
+    /// ```
+    pub fn checked_add_days(self, days: u64) -> Option<Self> {
+        let days: i64 = i64::try_from(days).ok()?;
+        Self::from_days(self.to_days().checked_add(days)?)
+    }
+
+    /// Subtract a number of days, returning `None` if the result is out of
+    /// range.
+    pub fn checked_sub_days(self, days: u64) -> Option<Self> {
+        let days: i64 = i64::try_from(days).ok()?;
+        Self::from_days(self.to_days().checked_sub(days)?)
+    }
+
+    /// Add a number of months, which may be negative.
+    ///
+    /// The day is clamped to the length of the resulting month, so 31 Sawan
+    /// plus two months is 30 Assu. Returns `None` if the result is out of
+    /// range.
+    ///
+    /// # Examples
+    /// ```
+    /// use nanakshahi::{NanakshahiDate, NanakshahiMonth};
+    ///
+    /// let date = NanakshahiDate::new(557, NanakshahiMonth::Sawan, 31)?;
+    /// let later = NanakshahiDate::new(557, NanakshahiMonth::Assu, 30)?;
+    /// assert_eq!(date.add_months(2), Some(later));
+    /// # Ok::<(), nanakshahi::NanakshahiError>(())
+    /// ```
+    pub fn add_months(self, months: i32) -> Option<Self> {
+        let total: i64 = self.year as i64 * 12 + self.month as i64 - 1 + months as i64;
+        let year: i32 = i32::try_from(total.div_euclid(12)).ok()?;
+        let month: NanakshahiMonth = NanakshahiMonth::ALL[total.rem_euclid(12) as usize];
+
+        Some(NanakshahiDate {
+            year,
+            month,
+            day: self.day.min(month.days(year)),
+        })
+    }
+
+    /// Add a number of years, which may be negative.
+    ///
+    /// 31 Phaggan is clamped to 30 Phaggan when the resulting year is not a
+    /// leap year. Returns `None` if the result is out of range.
+    pub fn add_years(self, years: i32) -> Option<Self> {
+        let year: i32 = self.year.checked_add(years)?;
+
+        Some(NanakshahiDate {
+            year,
+            month: self.month,
+            day: self.day.min(self.month.days(year)),
+        })
+    }
+
+    /// The signed duration between this date and an earlier one.
+    ///
+    /// # Examples
+    /// ```
+    /// use chrono::Duration;
+    /// use nanakshahi::{NanakshahiDate, NanakshahiMonth};
+    ///
+    /// let start = NanakshahiDate::new(556, NanakshahiMonth::Chet, 1)?;
+    /// let end = NanakshahiDate::new(557, NanakshahiMonth::Chet, 1)?;
+    /// assert_eq!(end.signed_duration_since(start), Duration::days(365));
+    /// # Ok::<(), nanakshahi::NanakshahiError>(())
+    /// ```
+    pub fn signed_duration_since(self, rhs: Self) -> Duration {
+        Duration::days(self.to_days() - rhs.to_days())
+    }
+
+    /// The Rata Die of this date, counting 1 January of year 1 in the
+    /// proleptic Gregorian calendar as day 1, as in *Calendrical
+    /// Calculations*.
+    ///
+    /// # Examples
+    /// ```
+    /// use nanakshahi::{NanakshahiDate, NanakshahiMonth};
+    ///
+    /// let date = NanakshahiDate::new(557, NanakshahiMonth::Chet, 1)?;
+    /// assert_eq!(date.to_rata_die(), 739324);
+    /// assert_eq!(NanakshahiDate::from_rata_die(739324), Some(date));
+    /// # Ok::<(), nanakshahi::NanakshahiError>(())
+    /// ```
+    pub fn to_rata_die(&self) -> i64 {
+        self.to_days() + RATA_DIE_OF_EPOCH
+    }
+
+    /// The date of a Rata Die, or `None` if the year is out of range.
+    pub fn from_rata_die(rata_die: i64) -> Option<Self> {
+        Self::from_days(rata_die.checked_sub(RATA_DIE_OF_EPOCH)?)
+    }
+
+    /// The Julian Day Number of this date, which is the Julian Day at noon.
+    ///
+    /// # Examples
+    /// ```
+    /// use nanakshahi::{NanakshahiDate, NanakshahiMonth};
+    ///
+    /// let date = NanakshahiDate::new(557, NanakshahiMonth::Chet, 1)?;
+    /// assert_eq!(date.to_jdn(), 2460749);
+    /// assert_eq!(NanakshahiDate::from_jdn(2460749), Some(date));
+    /// # Ok::<(), nanakshahi::NanakshahiError>(())
+    /// ```
+    pub fn to_jdn(&self) -> i64 {
+        self.to_rata_die() + JULIAN_DAY_OF_COMMON_ERA
+    }
+
+    /// The date of a Julian Day Number, or `None` if the year is out of
+    /// range.
+    pub fn from_jdn(jdn: i64) -> Option<Self> {
+        Self::from_rata_die(jdn.checked_sub(JULIAN_DAY_OF_COMMON_ERA)?)
+    }
+
+    /// Days elapsed since 1 Chet of year 0.
+    fn to_days(self) -> i64 {
+        days_before_year(self.year as i64) + self.ordinal() as i64 - 1
+    }
+
+    /// Inverse of [`NanakshahiDate::to_days`].
+    pub(crate) fn from_days(days: i64) -> Option<Self> {
+        let mut year: i64 = days.checked_mul(400)?.div_euclid(146097);
+        while days_before_year(year + 1) <= days {
+            year += 1;
+        }
+        while days_before_year(year) > days {
+            year -= 1;
+        }
+        let year: i32 = i32::try_from(year).ok()?;
+
+        // The leap day falls past the start of Phaggan, the last month.
+        let offset: i64 = days - days_before_year(year as i64);
+        let index: usize = DAYS_BEFORE_MONTHS.partition_point(|&start| start as i64 <= offset) - 1;
+
+        Some(NanakshahiDate {
+            year,
+            month: NanakshahiMonth::ALL[index],
+            day: (offset - DAYS_BEFORE_MONTHS[index] as i64 + 1) as u8,
+        })
+    }
+}
+
+impl From<NaiveDate> for NanakshahiDate {
+    /// Converts a Gregorian date, as [`crate::to`] does.
+    ///
+    /// # Examples
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use nanakshahi::{NanakshahiDate, NanakshahiMonth};
+    ///
+    /// let gregorian = NaiveDate::from_ymd_opt(2025, 3, 14).unwrap();
+    /// let date = NanakshahiDate::from(gregorian);
+    /// assert_eq!(date, NanakshahiDate::new(557, NanakshahiMonth::Chet, 1)?);
+    /// assert_eq!(NaiveDate::try_from(date)?, gregorian);
+    /// # Ok::<(), nanakshahi::NanakshahiError>(())
+    /// ```
+    fn from(date: NaiveDate) -> Self {
+        NanakshahiDate::from_rata_die(date.num_days_from_ce() as i64)
+            .expect("Every chrono date has a Nanakshahi year")
+    }
+}
+
+impl TryFrom<NanakshahiDate> for NaiveDate {
+    type Error = NanakshahiError;
+
+    /// Converts to a Gregorian date, failing with
+    /// [`NanakshahiError::YearOutOfRange`] outside the range of chrono.
+    fn try_from(date: NanakshahiDate) -> Result<Self, Self::Error> {
+        date.to_gregorian()
+    }
+}
+
+impl fmt::Display for NanakshahiDate {
+    /// Writes the date as year-month-day, such as "557-01-01".
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.format("%F"))
+    }
+}
+
+impl FromStr for NanakshahiDate {
+    type Err = ParseError;
+
+    /// Parses numeric dates such as "557-01-01" and written dates such as
+    /// "1 Vaisakh 557", "Vaisakh 1, 557 NS" or "੧ ਵੈਸਾਖ ੫੫੭".
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse::parse_any(s)
+    }
+}
+
+/// A year within its era, as returned by [`NanakshahiDate::year_ns`].
+pub(crate) fn year_ns(year: i32) -> (bool, u32) {
+    if year >= 1 {
+        (true, year as u32)
+    } else {
+        (false, (1 - year as i64) as u32)
+    }
+}
+
+/// Days in the months of a year before `month`.
+fn days_before_month(month: NanakshahiMonth) -> u32 {
+    DAYS_BEFORE_MONTHS[month as usize - 1] as u32
+}
+
+/// Days in the Nanakshahi years before `year`, counting from year 0.
+fn days_before_year(year: i64) -> i64 {
+    // Leap years up to and including a Gregorian year, offset by a constant.
+    let leap_years = |year: i64| year.div_euclid(4) - year.div_euclid(100) + year.div_euclid(400);
+    let epoch: i64 = EPOCH_ON_OR_AFTER_MID_MARCH as i64;
+
+    365 * year + leap_years(year + epoch) - leap_years(epoch)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(year: i32, month: NanakshahiMonth, day: u8) -> NanakshahiDate {
+        NanakshahiDate::new(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn test_ordinal() {
+        assert_eq!(date(557, NanakshahiMonth::Chet, 1).ordinal(), 1);
+        assert_eq!(date(556, NanakshahiMonth::Phaggan, 30).ordinal(), 365);
+        assert_eq!(date(555, NanakshahiMonth::Phaggan, 31).ordinal(), 366);
+    }
+
+    #[test]
+    fn test_weekday_matches_gregorian() {
+        use chrono::Datelike;
+
+        let start = date(-1000, NanakshahiMonth::Chet, 1);
+        for days in [0, 1, 6, 365, 1000, 365243, 565000] {
+            let date = start.checked_add_days(days).unwrap();
+            assert_eq!(
+                date.weekday(),
+                NanakshahiWeekday::from(date.to_gregorian().unwrap().weekday())
+            );
+        }
+    }
+
+    #[test]
+    fn test_checked_add_days_across_leap_year() {
+        let start = date(555, NanakshahiMonth::Phaggan, 1);
+
+        assert_eq!(
+            start.checked_add_days(30),
+            Some(date(555, NanakshahiMonth::Phaggan, 31))
+        );
+        assert_eq!(
+            start.checked_add_days(31),
+            Some(date(556, NanakshahiMonth::Chet, 1))
+        );
+    }
+
+    #[test]
+    fn test_checked_add_days_matches_gregorian() {
+        let start = date(500, NanakshahiMonth::Poh, 23);
+        let gregorian = start.to_gregorian().unwrap();
+
+        for days in [0, 1, 100, 365, 366, 1461, 40000] {
+            let end = start.checked_add_days(days).unwrap();
+            assert_eq!(
+                end.to_gregorian().unwrap(),
+                gregorian + Duration::days(days as i64)
+            );
+        }
+    }
+
+    #[test]
+    fn test_checked_add_days_before_epoch() {
+        let start = date(-100, NanakshahiMonth::Magh, 9);
+        let gregorian = start.to_gregorian().unwrap();
+
+        for days in [0, 1, 365, 36524, 146097] {
+            let end = start.checked_add_days(days).unwrap();
+            assert_eq!(
+                end.to_gregorian().unwrap(),
+                gregorian + Duration::days(days as i64)
+            );
+            assert_eq!(end.checked_sub_days(days), Some(start));
+        }
+    }
+
+    #[test]
+    fn test_year_ns() {
+        assert_eq!(date(557, NanakshahiMonth::Chet, 1).year_ns(), (true, 557));
+        assert_eq!(date(1, NanakshahiMonth::Chet, 1).year_ns(), (true, 1));
+        assert_eq!(date(0, NanakshahiMonth::Chet, 1).year_ns(), (false, 1));
+        assert_eq!(date(-1, NanakshahiMonth::Chet, 1).year_ns(), (false, 2));
+    }
+
+    #[test]
+    fn test_checked_sub_days() {
+        assert_eq!(
+            date(557, NanakshahiMonth::Chet, 1).checked_sub_days(1),
+            Some(date(556, NanakshahiMonth::Phaggan, 30))
+        );
+        assert_eq!(
+            date(0, NanakshahiMonth::Chet, 1).checked_sub_days(1),
+            Some(date(-1, NanakshahiMonth::Phaggan, 31))
+        );
+    }
+
+    #[test]
+    fn test_add_months() {
+        assert_eq!(
+            date(557, NanakshahiMonth::Poh, 3).add_months(3),
+            Some(date(558, NanakshahiMonth::Chet, 3))
+        );
+        assert_eq!(
+            date(557, NanakshahiMonth::Chet, 31).add_months(-1),
+            Some(date(556, NanakshahiMonth::Phaggan, 30))
+        );
+        assert_eq!(
+            date(0, NanakshahiMonth::Chet, 1).add_months(-1),
+            Some(date(-1, NanakshahiMonth::Phaggan, 1))
+        );
+        assert_eq!(
+            date(i32::MAX, NanakshahiMonth::Phaggan, 1).add_months(1),
+            None
+        );
+    }
+
+    #[test]
+    fn test_add_years() {
+        assert_eq!(
+            date(555, NanakshahiMonth::Phaggan, 31).add_years(1),
+            Some(date(556, NanakshahiMonth::Phaggan, 30))
+        );
+        assert_eq!(
+            date(555, NanakshahiMonth::Phaggan, 31).add_years(4),
+            Some(date(559, NanakshahiMonth::Phaggan, 31))
+        );
+    }
+
+    #[test]
+    fn test_signed_duration_since() {
+        let start = date(555, NanakshahiMonth::Chet, 1);
+        let end = date(556, NanakshahiMonth::Chet, 1);
+
+        assert_eq!(end.signed_duration_since(start), Duration::days(366));
+        assert_eq!(start.signed_duration_since(end), Duration::days(-366));
+    }
+
+    #[test]
+    fn test_rata_die_matches_gregorian() {
+        use chrono::Datelike;
+
+        let start = date(-2000, NanakshahiMonth::Chet, 1);
+        for days in [0, 1, 155, 364, 365, 1461, 146097, 900000] {
+            let date = start.checked_add_days(days).unwrap();
+            let gregorian = date.to_gregorian().unwrap();
+
+            assert_eq!(date.to_rata_die(), gregorian.num_days_from_ce() as i64);
+            assert_eq!(
+                NanakshahiDate::from_rata_die(date.to_rata_die()),
+                Some(date)
+            );
+            assert_eq!(NanakshahiDate::from_jdn(date.to_jdn()), Some(date));
+        }
+    }
+
+    #[test]
+    fn test_from_jdn() {
+        // The Julian Day epoch, 24 November 4714 BC in the proleptic Gregorian
+        // calendar.
+        let date = NanakshahiDate::from_jdn(0).unwrap();
+        assert_eq!(
+            date.to_gregorian(),
+            Ok(NaiveDate::from_ymd_opt(-4713, 11, 24).unwrap())
+        );
+        assert_eq!(NanakshahiDate::from_jdn(i64::MAX), None);
+        assert_eq!(NanakshahiDate::from_rata_die(i64::MIN), None);
+    }
+
+    #[test]
+    fn test_from_datetime_with_sunrise() {
+        use chrono::FixedOffset;
+        use chrono_tz::Asia::Kolkata;
+        use chrono_tz::Europe::London;
+
+        let sunrise = DayBoundary::Sunrise(Location::AMRITSAR);
+        let at = |hour, minute| {
+            Kolkata
+                .with_ymd_and_hms(2025, 3, 14, hour, minute, 0)
+                .unwrap()
+        };
+        let phaggan = date(556, NanakshahiMonth::Phaggan, 30);
+        let chet = date(557, NanakshahiMonth::Chet, 1);
+
+        assert_eq!(NanakshahiDate::from_datetime(&at(0, 0)), chet);
+        assert_eq!(
+            NanakshahiDate::from_datetime_with(&at(6, 30), sunrise),
+            phaggan
+        );
+        assert_eq!(
+            NanakshahiDate::from_datetime_with(&at(6, 45), sunrise),
+            chet
+        );
+
+        // The same instant gives the same date whatever its time zone.
+        let toronto = at(8, 30).with_timezone(&chrono_tz::America::Toronto);
+        assert_eq!(toronto.to_string(), "2025-03-13 23:00:00 EDT");
+        assert_eq!(
+            NanakshahiDate::from_datetime_with(&toronto, sunrise),
+            NanakshahiDate::from_datetime_with(&at(8, 30), sunrise)
+        );
+        assert_eq!(NanakshahiDate::from_datetime_with(&toronto, sunrise), chet);
+
+        // Where the sun does not rise, the day begins at 06:00.
+        let pole = Location::new(89.0, 0.0, 0.0, FixedOffset::east_opt(0).unwrap());
+        let winter = London.with_ymd_and_hms(2024, 12, 21, 5, 59, 0).unwrap();
+        assert_eq!(
+            NanakshahiDate::from_datetime_with(&winter, DayBoundary::Sunrise(pole)),
+            date(556, NanakshahiMonth::Poh, 7)
+        );
+    }
+}
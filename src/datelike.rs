@@ -0,0 +1,169 @@
+use crate::{is_leap_year, NanakshahiDate, NanakshahiMonth, NanakshahiWeekday};
+
+/// The year, month and day of a Nanakshahi date, in the manner of chrono's
+/// [`Datelike`](chrono::Datelike).
+///
+/// Numbers are returned as `u32` and counted from 1, as in chrono, with the
+/// `0`-suffixed methods counting from 0. Months are numbered from Chet.
+///
+/// # Examples
+/// ```
+/// use nanakshahi::{NanakshahiDate, NanakshahiDatelike, NanakshahiMonth};
+///
+/// let date = NanakshahiDate::new(557, NanakshahiMonth::Vaisakh, 10)?;
+/// assert_eq!((date.month(), date.day(), date.ordinal()), (2, 10, 41));
+///
+/// let later = date.with_month(12).and_then(|date| date.with_day(30));
+/// assert_eq!(later, Some(NanakshahiDate::new(557, NanakshahiMonth::Phaggan, 30)?));
+/// assert_eq!(date.with_day(32), None);
+/// # Ok::<(), nanakshahi::NanakshahiError>(())
+/// ```
+pub trait NanakshahiDatelike: Sized {
+    /// The year, numbered astronomically.
+    fn year(&self) -> i32;
+
+    /// The month, from 1 for Chet to 12 for Phaggan.
+    fn month(&self) -> u32;
+
+    /// The month, from 0 for Chet to 11 for Phaggan.
+    fn month0(&self) -> u32 {
+        self.month() - 1
+    }
+
+    /// The day of the month, starting from 1.
+    fn day(&self) -> u32;
+
+    /// The day of the month, starting from 0.
+    fn day0(&self) -> u32 {
+        self.day() - 1
+    }
+
+    /// The day of the year, starting from 1 on 1 Chet.
+    fn ordinal(&self) -> u32;
+
+    /// The day of the year, starting from 0 on 1 Chet.
+    fn ordinal0(&self) -> u32 {
+        self.ordinal() - 1
+    }
+
+    /// The day of the week.
+    fn weekday(&self) -> NanakshahiWeekday;
+
+    /// The same day and month in another year, or `None` if it does not
+    /// exist.
+    fn with_year(&self, year: i32) -> Option<Self>;
+
+    /// The same day in another month of the year, or `None` if it does not
+    /// exist.
+    fn with_month(&self, month: u32) -> Option<Self>;
+
+    /// Another day of the same month, or `None` if it does not exist.
+    fn with_day(&self, day: u32) -> Option<Self>;
+
+    /// Another day of the same year, or `None` if it does not exist.
+    fn with_ordinal(&self, ordinal: u32) -> Option<Self>;
+}
+
+impl NanakshahiDatelike for NanakshahiDate {
+    fn year(&self) -> i32 {
+        self.year
+    }
+
+    fn month(&self) -> u32 {
+        self.month.number() as u32
+    }
+
+    fn day(&self) -> u32 {
+        self.day as u32
+    }
+
+    fn ordinal(&self) -> u32 {
+        NanakshahiDate::ordinal(self)
+    }
+
+    fn weekday(&self) -> NanakshahiWeekday {
+        NanakshahiDate::weekday(self)
+    }
+
+    fn with_year(&self, year: i32) -> Option<Self> {
+        NanakshahiDate::new(year, self.month, self.day).ok()
+    }
+
+    fn with_month(&self, month: u32) -> Option<Self> {
+        let month: NanakshahiMonth = NanakshahiMonth::try_from(u8::try_from(month).ok()?).ok()?;
+        NanakshahiDate::new(self.year, month, self.day).ok()
+    }
+
+    fn with_day(&self, day: u32) -> Option<Self> {
+        NanakshahiDate::new(self.year, self.month, u8::try_from(day).ok()?).ok()
+    }
+
+    fn with_ordinal(&self, ordinal: u32) -> Option<Self> {
+        let days: u32 = if is_leap_year(self.year) { 366 } else { 365 };
+        if ordinal == 0 || ordinal > days {
+            return None;
+        }
+        let first: NanakshahiDate = NanakshahiDate {
+            year: self.year,
+            month: NanakshahiMonth::Chet,
+            day: 1,
+        };
+        first.checked_add_days(ordinal as u64 - 1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(year: i32, month: NanakshahiMonth, day: u8) -> NanakshahiDate {
+        NanakshahiDate::new(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn test_parts() {
+        let date = date(555, NanakshahiMonth::Phaggan, 31);
+
+        assert_eq!(NanakshahiDatelike::year(&date), 555);
+        assert_eq!((date.month(), date.month0()), (12, 11));
+        assert_eq!((date.day(), date.day0()), (31, 30));
+        assert_eq!((date.ordinal(), date.ordinal0()), (366, 365));
+        assert_eq!(
+            NanakshahiDatelike::ordinal(&date),
+            NanakshahiDate::ordinal(&date)
+        );
+        assert_eq!(
+            NanakshahiDatelike::weekday(&date),
+            NanakshahiDate::weekday(&date)
+        );
+    }
+
+    #[test]
+    fn test_with() {
+        let leap_day = date(555, NanakshahiMonth::Phaggan, 31);
+
+        assert_eq!(leap_day.with_year(556), None);
+        assert_eq!(
+            leap_day.with_year(559),
+            Some(date(559, NanakshahiMonth::Phaggan, 31))
+        );
+        assert_eq!(
+            leap_day.with_month(5),
+            Some(date(555, NanakshahiMonth::Sawan, 31))
+        );
+        assert_eq!(leap_day.with_month(6), None);
+        assert_eq!(leap_day.with_month(13), None);
+        assert_eq!(leap_day.with_day(0), None);
+        assert_eq!(leap_day.with_day(256), None);
+        assert_eq!(
+            leap_day.with_ordinal(1),
+            Some(date(555, NanakshahiMonth::Chet, 1))
+        );
+        assert_eq!(
+            leap_day.with_ordinal(156),
+            Some(date(555, NanakshahiMonth::Bhadon, 1))
+        );
+        assert_eq!(leap_day.with_ordinal(367), None);
+        assert_eq!(date(556, NanakshahiMonth::Chet, 1).with_ordinal(366), None);
+    }
+}
@@ -0,0 +1,121 @@
+use std::error::Error;
+use std::fmt;
+
+/// Errors returned when converting between the Nanakshahi and Gregorian
+/// calendars.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NanakshahiError {
+    /// The Nanakshahi month is not between 1 (Chet) and 12 (Phaggan).
+    InvalidMonth(u8),
+    /// The day does not exist in the given Nanakshahi month and year.
+    InvalidDay { year: i32, month: u8, day: u8 },
+    /// The year is outside the range of dates supported by chrono.
+    YearOutOfRange(i32),
+    /// The Gregorian date does not exist.
+    InvalidGregorianDate { year: i32, month: u8, day: u8 },
+    /// The Julian date does not exist, or falls in the days skipped when
+    /// switching to the Gregorian calendar.
+    InvalidJulianDate { year: i32, month: u8, day: u8 },
+    /// The tithi is not between 1 and 15 within its fortnight.
+    InvalidTithi(u8),
+    /// The lunar month does not occur in the given Bikrami Samvat year.
+    InvalidLunarMonth { samvat: i32, month: u8, adhik: bool },
+}
+
+impl fmt::Display for NanakshahiError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NanakshahiError::InvalidMonth(month) => {
+                write!(f, "invalid Nanakshahi month {month}, expected 1 to 12")
+            }
+            NanakshahiError::InvalidDay { year, month, day } => {
+                write!(
+                    f,
+                    "invalid day {day} for Nanakshahi month {month} of year {year}"
+                )
+            }
+            NanakshahiError::YearOutOfRange(year) => {
+                write!(f, "Nanakshahi year {year} is out of range")
+            }
+            NanakshahiError::InvalidGregorianDate { year, month, day } => {
+                write!(f, "invalid Gregorian date {year:04}-{month:02}-{day:02}")
+            }
+            NanakshahiError::InvalidJulianDate { year, month, day } => {
+                write!(f, "invalid Julian date {year:04}-{month:02}-{day:02}")
+            }
+            NanakshahiError::InvalidTithi(tithi) => {
+                write!(f, "invalid tithi {tithi}, expected 1 to 15")
+            }
+            NanakshahiError::InvalidLunarMonth {
+                samvat,
+                month,
+                adhik,
+            } => {
+                let adhik: &str = if *adhik { "adhik " } else { "" };
+                write!(f, "Samvat {samvat} has no {adhik}lunar month {month}")
+            }
+        }
+    }
+}
+
+impl Error for NanakshahiError {}
+
+/// The ways in which parsing a Nanakshahi date can fail.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParseErrorKind {
+    /// The input does not match the pattern at this position.
+    Invalid,
+    /// The input ended before the pattern was complete.
+    TooShort,
+    /// The input continues after the pattern was complete.
+    TooLong,
+    /// The pattern contains an unknown or malformed specifier.
+    BadFormat,
+    /// The pattern does not provide enough fields to determine a date.
+    NotEnough,
+    /// The fields are individually valid but contradict each other, such as a
+    /// weekday that does not match the date.
+    Impossible,
+    /// The fields describe a date that does not exist.
+    OutOfRange(NanakshahiError),
+}
+
+/// An error returned when parsing a Nanakshahi date from a string.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParseError {
+    position: usize,
+    kind: ParseErrorKind,
+}
+
+impl ParseError {
+    pub(crate) fn new(position: usize, kind: ParseErrorKind) -> Self {
+        ParseError { position, kind }
+    }
+
+    /// The byte offset in the input at which parsing failed.
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    /// What went wrong.
+    pub fn kind(&self) -> ParseErrorKind {
+        self.kind
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.kind {
+            ParseErrorKind::Invalid => write!(f, "unexpected input")?,
+            ParseErrorKind::TooShort => write!(f, "premature end of input")?,
+            ParseErrorKind::TooLong => write!(f, "trailing input")?,
+            ParseErrorKind::BadFormat => write!(f, "bad or unsupported format string")?,
+            ParseErrorKind::NotEnough => write!(f, "not enough fields to determine a date")?,
+            ParseErrorKind::Impossible => write!(f, "fields contradict each other")?,
+            ParseErrorKind::OutOfRange(error) => write!(f, "{error}")?,
+        }
+        write!(f, " at position {}", self.position)
+    }
+}
+
+impl Error for ParseError {}
@@ -0,0 +1,341 @@
+//! `strftime`-inspired formatting of Nanakshahi dates.
+//!
+//! # Specifiers
+//!
+//! | Spec. | Example   | Description                                                  |
+//! |-------|-----------|--------------------------------------------------------------|
+//! | `%Y`  | `557`     | The Nanakshahi year, numbered astronomically with a leading `-` before year 0. |
+//! | `%N`  | `557`     | The year within its era, from 1. Year 0 is 1 BNS.            |
+//! | `%m`  | `01`      | Month number (01--12), zero-padded to 2 digits.              |
+//! | `%B`  | `Vaisakh` | Full month name.                                             |
+//! | `%b`  | `Vai`     | Abbreviated month name. Three letters in Latin script, the full name otherwise. |
+//! | `%d`  | `08`      | Day number (01--31), zero-padded to 2 digits.                |
+//! | `%e`  | ` 8`      | Same as `%d` but space-padded.                               |
+//! | `%A`  | `Aitvar`  | Full weekday name.                                           |
+//! | `%a`  | `Ait`     | Abbreviated weekday name, without the "var" suffix.          |
+//! | `%w`  | `0`       | Aitvar = 0, Somvar = 1, ..., Shanivar = 6.                   |
+//! | `%j`  | `032`     | Day of the year (001--366), zero-padded to 3 digits.         |
+//! | `%E`  | `NS`      | Era suffix, "NS" from year 1 and "BNS" before it.            |
+//! | `%F`  | `557-01-08` | Year-month-day format. Same as `%Y-%m-%d`.                 |
+//! | `%%`  | `%`       | A literal percent sign.                                      |
+//! | `%n`  |           | A literal newline.                                           |
+//! | `%t`  |           | A literal tab.                                               |
+//!
+//! As in chrono, numeric specifiers accept a padding modifier: `%-d` suppresses
+//! padding, `%_d` pads with spaces and `%0e` pads with zeros.
+//!
+//! Names and digits are written in the [`Script`] passed to
+//! [`NanakshahiDate::format_in`], and in Latin script by
+//! [`NanakshahiDate::format`]. Dates of other calendar variants are formatted
+//! the same way by [`VariantDate::format_in`](crate::VariantDate::format_in).
+
+use std::fmt;
+
+use crate::date::year_ns;
+use crate::{NanakshahiDate, NanakshahiMonth, NanakshahiWeekday, Script, VariantDate};
+
+const NANAKSHAHI_MONTH_ABBREVIATIONS: [&str; 12] = [
+    "Che", "Vai", "Jet", "Har", "Saw", "Bha", "Ass", "Kat", "Mgr", "Poh", "Mag", "Pha",
+];
+
+/// Padding applied to a numeric specifier.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Pad {
+    None,
+    Zero,
+    Space,
+}
+
+/// A single piece of a format pattern.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Item<'a> {
+    /// Text copied as is.
+    Literal(&'a str),
+    /// A specifier such as `%d`, with an optional padding modifier.
+    Specifier { spec: char, pad: Option<Pad> },
+    /// A malformed or unterminated specifier.
+    Invalid,
+}
+
+/// Splits a format pattern into [`Item`]s.
+#[derive(Debug, Clone)]
+pub(crate) struct StrftimeItems<'a> {
+    remainder: &'a str,
+}
+
+impl<'a> StrftimeItems<'a> {
+    pub(crate) fn new(pattern: &'a str) -> Self {
+        StrftimeItems { remainder: pattern }
+    }
+}
+
+impl<'a> Iterator for StrftimeItems<'a> {
+    type Item = Item<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remainder.is_empty() {
+            return None;
+        }
+
+        let Some(rest) = self.remainder.strip_prefix('%') else {
+            let end: usize = self.remainder.find('%').unwrap_or(self.remainder.len());
+            let (literal, rest) = self.remainder.split_at(end);
+            self.remainder = rest;
+            return Some(Item::Literal(literal));
+        };
+
+        let mut chars = rest.chars();
+        let mut spec: Option<char> = chars.next();
+        let pad: Option<Pad> = match spec {
+            Some('-') => Some(Pad::None),
+            Some('0') => Some(Pad::Zero),
+            Some('_') => Some(Pad::Space),
+            _ => None,
+        };
+        if pad.is_some() {
+            spec = chars.next();
+        }
+        self.remainder = chars.as_str();
+
+        match spec {
+            Some('%') if pad.is_none() => Some(Item::Literal("%")),
+            Some('n') if pad.is_none() => Some(Item::Literal("\n")),
+            Some('t') if pad.is_none() => Some(Item::Literal("\t")),
+            Some(spec) => Some(Item::Specifier { spec, pad }),
+            None => Some(Item::Invalid),
+        }
+    }
+}
+
+/// A Nanakshahi date paired with a format pattern, rendered when displayed.
+///
+/// Displaying returns [`fmt::Error`] if the pattern contains an unknown
+/// specifier.
+#[derive(Debug, Clone)]
+pub struct DelayedFormat<'a> {
+    year: i32,
+    month: NanakshahiMonth,
+    day: u8,
+    ordinal: u32,
+    weekday: NanakshahiWeekday,
+    pattern: &'a str,
+    script: Script,
+}
+
+impl<'a> DelayedFormat<'a> {
+    pub(crate) fn new(date: NanakshahiDate, pattern: &'a str, script: Script) -> Self {
+        DelayedFormat {
+            year: date.year,
+            month: date.month,
+            day: date.day,
+            ordinal: date.ordinal(),
+            weekday: date.weekday(),
+            pattern,
+            script,
+        }
+    }
+
+    pub(crate) fn from_variant(date: VariantDate, pattern: &'a str, script: Script) -> Self {
+        DelayedFormat {
+            year: date.year(),
+            month: date.month(),
+            day: date.day(),
+            ordinal: date.ordinal(),
+            weekday: date.weekday(),
+            pattern,
+            script,
+        }
+    }
+
+    fn write_pattern(&self, f: &mut fmt::Formatter<'_>, pattern: &str) -> fmt::Result {
+        for item in StrftimeItems::new(pattern) {
+            match item {
+                Item::Literal(literal) => f.write_str(literal)?,
+                Item::Specifier { spec, pad } => self.write_specifier(f, spec, pad)?,
+                Item::Invalid => return Err(fmt::Error),
+            }
+        }
+        Ok(())
+    }
+
+    fn write_specifier(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        spec: char,
+        pad: Option<Pad>,
+    ) -> fmt::Result {
+        let script: Script = self.script;
+        let (in_era, year_in_era): (bool, u32) = year_ns(self.year);
+
+        match spec {
+            'Y' => {
+                if self.year < 0 {
+                    f.write_str("-")?;
+                }
+                self.write_number(
+                    f,
+                    self.year.unsigned_abs() as u64,
+                    1,
+                    pad.unwrap_or(Pad::None),
+                )
+            }
+            'N' => self.write_number(f, year_in_era as u64, 1, pad.unwrap_or(Pad::None)),
+            'm' => self.write_number(f, self.month.number() as u64, 2, pad.unwrap_or(Pad::Zero)),
+            'd' => self.write_number(f, self.day as u64, 2, pad.unwrap_or(Pad::Zero)),
+            'e' => self.write_number(f, self.day as u64, 2, pad.unwrap_or(Pad::Space)),
+            'j' => self.write_number(f, self.ordinal as u64, 3, pad.unwrap_or(Pad::Zero)),
+            'w' => self.write_number(
+                f,
+                self.weekday.num_days_from_aitvar() as u64,
+                1,
+                pad.unwrap_or(Pad::None),
+            ),
+            _ if pad.is_some() => Err(fmt::Error),
+            'B' => f.write_str(self.month.name_in(script)),
+            'b' => f.write_str(short_month_name(self.month, script)),
+            'A' => f.write_str(self.weekday.name_in(script)),
+            'a' => f.write_str(short_weekday_name(self.weekday, script)),
+            'E' => f.write_str(era(script, in_era)),
+            'F' => self.write_pattern(f, "%Y-%m-%d"),
+            _ => Err(fmt::Error),
+        }
+    }
+
+    fn write_number(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        number: u64,
+        width: usize,
+        pad: Pad,
+    ) -> fmt::Result {
+        let digits: String = self.script.numeral(number);
+        let length: usize = digits.chars().count();
+        if length < width {
+            let fill: char = match pad {
+                Pad::None => return f.write_str(&digits),
+                Pad::Zero => self.script.digits()[0],
+                Pad::Space => ' ',
+            };
+            for _ in length..width {
+                write!(f, "{fill}")?;
+            }
+        }
+        f.write_str(&digits)
+    }
+}
+
+impl fmt::Display for DelayedFormat<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.write_pattern(f, self.pattern)
+    }
+}
+
+pub(crate) fn short_month_name(month: NanakshahiMonth, script: Script) -> &'static str {
+    match script {
+        Script::Latin => NANAKSHAHI_MONTH_ABBREVIATIONS[month as usize - 1],
+        _ => month.name_in(script),
+    }
+}
+
+pub(crate) fn short_weekday_name(weekday: NanakshahiWeekday, script: Script) -> &'static str {
+    let suffix: &str = match script {
+        Script::Latin => "var",
+        Script::Gurmukhi => "ਵਾਰ",
+        Script::Shahmukhi => "وار",
+        Script::Devanagari => "वार",
+    };
+    let name: &'static str = weekday.name_in(script);
+    name.strip_suffix(suffix).unwrap_or(name)
+}
+
+/// The era suffix for years in (`true`) or before (`false`) the Nanakshahi era.
+pub(crate) fn era(script: Script, nanakshahi: bool) -> &'static str {
+    match (script, nanakshahi) {
+        (Script::Latin, true) => "NS",
+        (Script::Latin, false) => "BNS",
+        (Script::Gurmukhi, true) => "ਨਾ:ਸ਼ਾ:",
+        (Script::Gurmukhi, false) => "ਨਾ:ਸ਼ਾ: ਪੂਰਵ",
+        (Script::Shahmukhi, true) => "ن:ش:",
+        (Script::Shahmukhi, false) => "قبل ن:ش:",
+        (Script::Devanagari, true) => "ना:शा:",
+        (Script::Devanagari, false) => "ना:शा: पूर्व",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::NanakshahiMonth;
+
+    fn date(year: i32, month: NanakshahiMonth, day: u8) -> NanakshahiDate {
+        NanakshahiDate::new(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn test_format_numeric() {
+        let date = date(557, NanakshahiMonth::Vaisakh, 8);
+
+        assert_eq!(date.format("%F").to_string(), "557-02-08");
+        assert_eq!(date.format("%Y/%-m/%-d").to_string(), "557/2/8");
+        assert_eq!(date.format("%e|%_m|%j").to_string(), " 8| 2|039");
+        assert_eq!(date.format("%w").to_string(), "1");
+    }
+
+    #[test]
+    fn test_format_names() {
+        let date = date(557, NanakshahiMonth::Chet, 1);
+
+        assert_eq!(date.format("%-d %B %Y %E").to_string(), "1 Chet 557 NS");
+        assert_eq!(date.format("%a, %-d %b").to_string(), "Shukar, 1 Che");
+        assert_eq!(date.format("%A").to_string(), "Shukarvar");
+        assert_eq!(date.add_months(8).unwrap().format("%b").to_string(), "Mgr");
+        assert_eq!(date.add_months(10).unwrap().format("%b").to_string(), "Mag");
+    }
+
+    #[test]
+    fn test_format_in_gurmukhi() {
+        let date = date(557, NanakshahiMonth::Chet, 1);
+
+        assert_eq!(
+            date.format_in("%B %-d, %Y", Script::Gurmukhi).to_string(),
+            "ਚੇਤ ੧, ੫੫੭"
+        );
+        assert_eq!(date.format_in("%d", Script::Gurmukhi).to_string(), "੦੧");
+        assert_eq!(date.format_in("%a", Script::Gurmukhi).to_string(), "ਸ਼ੁੱਕਰ");
+    }
+
+    #[test]
+    fn test_format_before_epoch() {
+        let date = date(-4, NanakshahiMonth::Chet, 1);
+
+        assert_eq!(date.format("%F").to_string(), "-4-01-01");
+        assert_eq!(date.format("%-d %B %N %E").to_string(), "1 Chet 5 BNS");
+        assert_eq!(
+            date.add_years(4).unwrap().format("%N %E").to_string(),
+            "1 BNS"
+        );
+        assert_eq!(
+            date.add_years(5).unwrap().format("%N %E").to_string(),
+            "1 NS"
+        );
+    }
+
+    #[test]
+    fn test_format_literals() {
+        let date = date(557, NanakshahiMonth::Chet, 1);
+
+        assert_eq!(date.format("100%% %Y%n").to_string(), "100% 557\n");
+    }
+
+    #[test]
+    fn test_format_invalid_specifier() {
+        use std::fmt::Write;
+
+        let date = date(557, NanakshahiMonth::Chet, 1);
+        let mut output = String::new();
+
+        assert!(write!(output, "{}", date.format("%Q")).is_err());
+        assert!(write!(output, "{}", date.format("%")).is_err());
+        assert!(write!(output, "{}", date.format("%-B")).is_err());
+    }
+}
@@ -0,0 +1,412 @@
+//! Export of observances, Sangrand and Puranmashi as an iCalendar file
+//! (RFC 5545), for subscription in calendar applications.
+//!
+//! Every event lasts one whole day. Its summary gives the English and
+//! Gurmukhi names and its description the Nanakshahi date in both scripts.
+//! Each event has a UID made from its Gregorian date and name, so that a
+//! regenerated file updates the same events rather than duplicating them.
+
+use std::ops::RangeInclusive;
+
+use chrono::{DateTime, Datelike, Duration, NaiveDate, Utc};
+
+use crate::lunar::{self, MoonPhase};
+use crate::observances;
+use crate::{CalendarVariant, Location, NanakshahiError, NanakshahiMonth, Script, VariantDate};
+
+/// The longest line allowed by RFC 5545, in octets, before folding.
+const LINE_LENGTH: usize = 75;
+
+/// What to include in an exported calendar, and how to date it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct IcsOptions {
+    /// The calendar variant by which observances are dated.
+    pub variant: CalendarVariant,
+    /// Include the gurpurabs and other observances.
+    pub observances: bool,
+    /// Include Sangrand on the first day of each month under the variant.
+    pub sangrand: bool,
+    /// Include the day observed as Puranmashi.
+    pub puranmashi: bool,
+    /// Add a reminder this long before the start of each day.
+    pub reminder: Option<Duration>,
+    /// The place by which lunar dates are reckoned.
+    pub location: Location,
+    /// The time at which the events are stamped as created.
+    pub timestamp: DateTime<Utc>,
+}
+
+impl Default for IcsOptions {
+    /// Observances and Sangrand of the original calendar, reckoned at
+    /// Amritsar, without reminders.
+    fn default() -> Self {
+        IcsOptions {
+            variant: CalendarVariant::default(),
+            observances: true,
+            sangrand: true,
+            puranmashi: false,
+            reminder: None,
+            location: Location::AMRITSAR,
+            timestamp: Utc::now(),
+        }
+    }
+}
+
+/// An all-day event.
+struct Event {
+    uid: String,
+    date: NaiveDate,
+    summary: String,
+    description: String,
+}
+
+/// An iCalendar file with the events of a range of Nanakshahi years.
+///
+/// # Errors
+/// Returns [`NanakshahiError::YearOutOfRange`] if a year cannot be
+/// converted.
+///
+/// # Examples
+/// ```
+/// use chrono::Duration;
+/// use nanakshahi::ics::{self, IcsOptions};
+///
+/// let options = IcsOptions {
+///     reminder: Some(Duration::hours(12)),
+///     ..IcsOptions::default()
+/// };
+/// let calendar = ics::calendar(557..=557, &options)?;
+///
+/// assert!(calendar.starts_with("BEGIN:VCALENDAR\r\n"));
+/// assert!(calendar.contains("DTSTART;VALUE=DATE:20250414\r\n"));
+/// assert!(calendar.contains("SUMMARY:Vaisakhi (Khalsa Sajna Divas) / "));
+/// assert!(calendar.contains("TRIGGER:-PT720M\r\n"));
+/// # Ok::<(), nanakshahi::NanakshahiError>(())
+/// ```
+pub fn calendar(
+    years: RangeInclusive<i32>,
+    options: &IcsOptions,
+) -> Result<String, NanakshahiError> {
+    let mut events: Vec<Event> = Vec::new();
+    for year in years {
+        if options.observances {
+            observance_events(year, options, &mut events)?;
+        }
+        if options.sangrand {
+            sangrand_events(year, options, &mut events)?;
+        }
+        if options.puranmashi {
+            puranmashi_events(year, options, &mut events)?;
+        }
+    }
+    events.sort_by(|a, b| (a.date, &a.uid).cmp(&(b.date, &b.uid)));
+
+    let mut output: String = String::new();
+    line(&mut output, "BEGIN:VCALENDAR");
+    line(&mut output, "VERSION:2.0");
+    line(&mut output, "PRODID:-//nanakshahi//Nanakshahi Calendar//EN");
+    line(&mut output, "CALSCALE:GREGORIAN");
+    line(&mut output, "X-WR-CALNAME:Nanakshahi Calendar");
+    let stamp: String = options.timestamp.format("%Y%m%dT%H%M%SZ").to_string();
+    for event in &events {
+        write_event(&mut output, event, &stamp, options.reminder);
+    }
+    line(&mut output, "END:VCALENDAR");
+    Ok(output)
+}
+
+fn observance_events(
+    year: i32,
+    options: &IcsOptions,
+    events: &mut Vec<Event>,
+) -> Result<(), NanakshahiError> {
+    let location: &Location = &options.location;
+    for occurrence in observances::observances_in_year_with(year, options.variant, location)? {
+        let observance = occurrence.observance;
+        events.push(event(
+            occurrence.gregorian,
+            observance.name,
+            observance.gurmukhi_name,
+            dates(occurrence.date),
+        ));
+    }
+    for occurrence in observances::lunar_observances_in_year(year, location)? {
+        let observance = occurrence.observance;
+        let date: VariantDate = variant_date(options.variant, occurrence.gregorian)?;
+        events.push(event(
+            occurrence.gregorian,
+            observance.name,
+            observance.gurmukhi_name,
+            dates(date),
+        ));
+    }
+    Ok(())
+}
+
+fn sangrand_events(
+    year: i32,
+    options: &IcsOptions,
+    events: &mut Vec<Event>,
+) -> Result<(), NanakshahiError> {
+    for month in NanakshahiMonth::ALL {
+        let gregorian: NaiveDate = options.variant.from(year, month.number(), 1)?;
+        events.push(event(
+            gregorian,
+            &format!("Sangrand {}", month.name()),
+            &format!("ਸੰਗਰਾਂਦ {}", month.name_in(Script::Gurmukhi)),
+            dates(variant_date(options.variant, gregorian)?),
+        ));
+    }
+    Ok(())
+}
+
+fn puranmashi_events(
+    year: i32,
+    options: &IcsOptions,
+    events: &mut Vec<Event>,
+) -> Result<(), NanakshahiError> {
+    let phases = lunar::moon_phases_in(year, &options.location.offset)?;
+    for phase in phases.filter(|event| event.phase == MoonPhase::Puranmashi) {
+        let Some(lunar) = phase.lunar_date() else {
+            continue;
+        };
+        let gregorian: NaiveDate = phase.observed_date(&options.location)?;
+        events.push(event(
+            gregorian,
+            &MoonPhase::Puranmashi.name_in(Script::Latin),
+            &MoonPhase::Puranmashi.name_in(Script::Gurmukhi),
+            format!(
+                "{}\n{}\n{}",
+                lunar.to_string_in(Script::Latin),
+                lunar.to_string_in(Script::Gurmukhi),
+                dates(variant_date(options.variant, gregorian)?)
+            ),
+        ));
+    }
+    Ok(())
+}
+
+fn event(date: NaiveDate, name: &str, gurmukhi_name: &str, description: String) -> Event {
+    Event {
+        uid: format!("{}-{}@nanakshahi", date.format("%Y%m%d"), slug(name)),
+        date,
+        summary: format!("{name} / {gurmukhi_name}"),
+        description,
+    }
+}
+
+/// The Nanakshahi date of a Gregorian date under a variant.
+fn variant_date(variant: CalendarVariant, date: NaiveDate) -> Result<VariantDate, NanakshahiError> {
+    variant.to(date.year(), date.month() as u8, date.day() as u8)
+}
+
+/// A Nanakshahi date in Latin and Gurmukhi script, on two lines.
+fn dates(date: VariantDate) -> String {
+    format!(
+        "{}\n{}",
+        date.format_in("%-d %B %N %E", Script::Latin),
+        date.format_in("%-d %B %N %E", Script::Gurmukhi)
+    )
+}
+
+fn write_event(output: &mut String, event: &Event, stamp: &str, reminder: Option<Duration>) {
+    line(output, "BEGIN:VEVENT");
+    line(output, &format!("UID:{}", event.uid));
+    line(output, &format!("DTSTAMP:{stamp}"));
+    line(
+        output,
+        &format!("DTSTART;VALUE=DATE:{}", event.date.format("%Y%m%d")),
+    );
+    if let Some(end) = event.date.succ_opt() {
+        line(
+            output,
+            &format!("DTEND;VALUE=DATE:{}", end.format("%Y%m%d")),
+        );
+    }
+    line(output, &format!("SUMMARY:{}", escape(&event.summary)));
+    line(
+        output,
+        &format!("DESCRIPTION:{}", escape(&event.description)),
+    );
+    line(output, "TRANSP:TRANSPARENT");
+    if let Some(reminder) = reminder {
+        let minutes: i64 = reminder.num_minutes();
+        let sign: &str = if minutes >= 0 { "-" } else { "" };
+        line(output, "BEGIN:VALARM");
+        line(output, "ACTION:DISPLAY");
+        line(output, &format!("DESCRIPTION:{}", escape(&event.summary)));
+        line(
+            output,
+            &format!("TRIGGER:{sign}PT{}M", minutes.unsigned_abs()),
+        );
+        line(output, "END:VALARM");
+    }
+    line(output, "END:VEVENT");
+}
+
+/// Write a content line, folded to [`LINE_LENGTH`] octets without splitting
+/// a character.
+fn line(output: &mut String, content: &str) {
+    let mut limit: usize = LINE_LENGTH;
+    let mut start: usize = 0;
+    for (index, c) in content.char_indices() {
+        if index + c.len_utf8() - start > limit {
+            output.push_str(&content[start..index]);
+            output.push_str("\r\n ");
+            start = index;
+            // The leading space counts towards the length of folded lines.
+            limit = LINE_LENGTH - 1;
+        }
+    }
+    output.push_str(&content[start..]);
+    output.push_str("\r\n");
+}
+
+/// Escape text for a TEXT property value.
+fn escape(text: &str) -> String {
+    let mut escaped: String = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '\\' | ';' | ',' => {
+                escaped.push('\\');
+                escaped.push(c);
+            }
+            '\n' => escaped.push_str("\\n"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// A lowercase ASCII identifier for a name, such as "prakash-guru-nanak-dev-ji".
+fn slug(name: &str) -> String {
+    name.split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(|word| word.to_ascii_lowercase())
+        .collect::<Vec<String>>()
+        .join("-")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn options() -> IcsOptions {
+        IcsOptions {
+            timestamp: Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap(),
+            ..IcsOptions::default()
+        }
+    }
+
+    /// The unfolded content lines of a calendar.
+    fn lines(calendar: &str) -> Vec<String> {
+        calendar
+            .replace("\r\n ", "")
+            .split("\r\n")
+            .map(str::to_string)
+            .collect()
+    }
+
+    #[test]
+    fn test_calendar() {
+        let calendar = calendar(557..=557, &options()).unwrap();
+        let lines = lines(&calendar);
+
+        assert!(calendar.ends_with("END:VCALENDAR\r\n"));
+        assert!(calendar.split("\r\n").all(|line| line.len() <= LINE_LENGTH));
+        let events: usize = lines.iter().filter(|line| *line == "BEGIN:VEVENT").count();
+        // Hola Mohalla falls twice in 557, so count the lunar observances
+        // rather than the catalog.
+        let lunar: usize = observances::lunar_observances_in_year(557, &Location::AMRITSAR)
+            .unwrap()
+            .len();
+        assert_eq!(events, observances::OBSERVANCES.len() + lunar + 12);
+
+        let start = lines
+            .iter()
+            .position(|line| line == "UID:20250414-vaisakhi-khalsa-sajna-divas@nanakshahi")
+            .unwrap();
+        assert_eq!(
+            &lines[start + 1..start + 7],
+            [
+                "DTSTAMP:20250101T000000Z",
+                "DTSTART;VALUE=DATE:20250414",
+                "DTEND;VALUE=DATE:20250415",
+                "SUMMARY:Vaisakhi (Khalsa Sajna Divas) / ਵੈਸਾਖੀ (ਖ਼ਾਲਸਾ ਸਾਜਨਾ ਦਿਵਸ)",
+                "DESCRIPTION:1 Vaisakh 557 NS\\n੧ ਵੈਸਾਖ ੫੫੭ ਨਾ:ਸ਼ਾ:",
+                "TRANSP:TRANSPARENT",
+            ]
+        );
+        assert!(lines.contains(&"UID:20250414-sangrand-vaisakh@nanakshahi".to_string()));
+        assert!(!lines.iter().any(|line| line == "BEGIN:VALARM"));
+    }
+
+    #[test]
+    fn test_amended_variant_and_puranmashi() {
+        let options = IcsOptions {
+            variant: CalendarVariant::Amended2010,
+            observances: false,
+            puranmashi: true,
+            reminder: Some(Duration::days(1)),
+            ..options()
+        };
+        let lines = lines(&calendar(557..=557, &options).unwrap());
+
+        // Sawan began on 16 July 2025 under the amended calendar.
+        let start = lines
+            .iter()
+            .position(|line| line == "UID:20250716-sangrand-sawan@nanakshahi")
+            .unwrap();
+        assert_eq!(
+            lines[start + 5],
+            "DESCRIPTION:1 Sawan 557 NS\\n੧ ਸਾਵਣ ੫੫੭ ਨਾ:ਸ਼ਾ:"
+        );
+
+        // Kattak Puranmashi, Guru Nanak's Prakash Purab, on 5 November 2025.
+        assert!(lines.contains(&"UID:20251105-puranmashi@nanakshahi".to_string()));
+        assert!(lines.contains(&"TRIGGER:-PT1440M".to_string()));
+        let puranmashis: usize = lines
+            .iter()
+            .filter(|line| line.starts_with("SUMMARY:Puranmashi"))
+            .count();
+        assert!((12..=13).contains(&puranmashis), "{puranmashis}");
+    }
+
+    #[test]
+    fn test_sangrand_on_first_of_month() {
+        for variant in CalendarVariant::ALL {
+            let options = IcsOptions {
+                variant,
+                observances: false,
+                ..options()
+            };
+            let lines = lines(&calendar(556..=557, &options).unwrap());
+            let descriptions: Vec<&String> = lines
+                .iter()
+                .filter(|line| line.starts_with("DESCRIPTION:"))
+                .collect();
+
+            assert_eq!(descriptions.len(), 24);
+            assert!(
+                descriptions
+                    .iter()
+                    .all(|line| line.starts_with("DESCRIPTION:1 ")),
+                "{variant:?}: {descriptions:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_line_folding() {
+        let mut output = String::new();
+        let text: String = "ੴ".repeat(40);
+        line(&mut output, &format!("SUMMARY:{text}"));
+
+        let lines: Vec<&str> = output.trim_end_matches("\r\n").split("\r\n").collect();
+        assert!(lines.len() > 1);
+        assert!(lines.iter().all(|line| line.len() <= LINE_LENGTH));
+        assert_eq!(lines.concat().replace(" ੴ", "ੴ"), format!("SUMMARY:{text}"));
+        assert_eq!(escape("a,b;c\\d\ne"), "a\\,b\\;c\\\\d\\ne");
+    }
+}
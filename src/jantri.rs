@@ -0,0 +1,948 @@
+//! Printable jantris, wall calendars of a Nanakshahi year, as self-contained
+//! SVG or HTML.
+//!
+//! A jantri has a page for each month, or the whole year on one page. Each
+//! month is a grid of weeks from Aitvar to Shanivar, with the Nanakshahi day
+//! in Gurmukhi numerals and the Gregorian date beside it. Sangrand is marked
+//! by a bar across the top of the day and Puranmashi and Masya by an open or
+//! filled moon. Days with a gurpurab or other observance are shaded, and
+//! the observances are listed under the grids in Gurmukhi and English.
+//!
+//! Colours and fonts come from a [`Theme`]. Fonts are named rather than
+//! embedded, so the fonts of the theme should be installed where the file is
+//! viewed or printed.
+
+use std::collections::BTreeMap;
+use std::ops::RangeInclusive;
+
+use chrono::{Datelike, Duration, NaiveDate};
+
+use crate::format::short_weekday_name;
+use crate::lunar::{self, MoonPhase};
+use crate::observances;
+use crate::{
+    CalendarVariant, Location, NanakshahiDate, NanakshahiError, NanakshahiMonth, NanakshahiWeekday,
+    Script,
+};
+
+/// The width of a page, in user units. Pages have the proportions of A4.
+const PAGE_WIDTH: f64 = 840.0;
+const PAGE_HEIGHT: f64 = 1188.0;
+const MARGIN: f64 = 35.0;
+/// The number of months in each row of a one-page jantri.
+const MONTHS_PER_ROW: usize = 3;
+
+/// How the months of a jantri are laid out.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Layout {
+    /// A page for each month, with its observances under the grid.
+    #[default]
+    Monthly,
+    /// The whole year on one page, with every observance under the grids.
+    OnePage,
+}
+
+/// The colours and fonts of a jantri.
+///
+/// Colours are any CSS colour, such as "#1f3c88" or "navy", and fonts are
+/// CSS font family lists.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Theme {
+    /// The colour of the page.
+    pub background: String,
+    /// The colour of the days and of most text.
+    pub text: String,
+    /// The colour of Gregorian dates and English names.
+    pub muted: String,
+    /// The colour of titles, weekday names and Sangrand.
+    pub heading: String,
+    /// The colour of the days of observances.
+    pub accent: String,
+    /// The shading of the days of observances.
+    pub highlight: String,
+    /// The colour of the grid lines.
+    pub grid: String,
+    /// The font of Gurmukhi text and numerals.
+    pub gurmukhi_font: String,
+    /// The font of English text and Gregorian dates.
+    pub latin_font: String,
+}
+
+impl Default for Theme {
+    /// Dark text on white, with titles in blue and observances in saffron.
+    fn default() -> Self {
+        Theme {
+            background: "#ffffff".to_string(),
+            text: "#1a1a1a".to_string(),
+            muted: "#6b6b6b".to_string(),
+            heading: "#1f3c88".to_string(),
+            accent: "#c75b00".to_string(),
+            highlight: "#fdf0e1".to_string(),
+            grid: "#c8c8c8".to_string(),
+            gurmukhi_font: "'Noto Sans Gurmukhi', 'Raavi', sans-serif".to_string(),
+            latin_font: "'Noto Sans', 'Helvetica', 'Arial', sans-serif".to_string(),
+        }
+    }
+}
+
+/// What to include in a jantri, and how to draw it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct JantriOptions {
+    /// The calendar variant by which months are laid out.
+    pub variant: CalendarVariant,
+    pub layout: Layout,
+    /// Mark and list the gurpurabs and other observances.
+    pub observances: bool,
+    /// Mark Sangrand on the first day of each month under the variant.
+    pub sangrand: bool,
+    /// Mark the days observed as Puranmashi and Masya.
+    pub moon_phases: bool,
+    /// The place by which lunar dates are reckoned.
+    pub location: Location,
+    pub theme: Theme,
+}
+
+impl Default for JantriOptions {
+    /// A page for each month of the original calendar, with observances,
+    /// Sangrand, Puranmashi and Masya reckoned at Amritsar.
+    fn default() -> Self {
+        JantriOptions {
+            variant: CalendarVariant::default(),
+            layout: Layout::default(),
+            observances: true,
+            sangrand: true,
+            moon_phases: true,
+            location: Location::AMRITSAR,
+            theme: Theme::default(),
+        }
+    }
+}
+
+/// What is marked on a day.
+#[derive(Default)]
+struct Marks {
+    sangrand: bool,
+    phase: Option<MoonPhase>,
+    /// The English and Gurmukhi names of the observances.
+    observances: Vec<(&'static str, &'static str)>,
+}
+
+/// The days of a month under a variant.
+struct Month {
+    month: NanakshahiMonth,
+    first: NaiveDate,
+    days: u8,
+}
+
+impl Month {
+    fn dates(&self) -> impl Iterator<Item = (u8, NaiveDate)> + '_ {
+        (1..=self.days).map(|day| (day, self.first + Duration::days(day as i64 - 1)))
+    }
+
+    fn weeks(&self) -> u32 {
+        let offset: u32 = self.first.weekday().num_days_from_sunday();
+        (offset + self.days as u32).div_ceil(7)
+    }
+}
+
+/// The sizes of a month grid.
+struct Metrics {
+    cell_width: f64,
+    cell_height: f64,
+    title_size: f64,
+    day_size: f64,
+    text_size: f64,
+    /// The space between the edge of a day and its contents.
+    padding: f64,
+    /// The radius of the moon of Puranmashi and Masya.
+    moon_radius: f64,
+    /// Name Sangrand, Puranmashi and Masya in the day, as well as marking
+    /// them.
+    labels: bool,
+}
+
+const FULL: Metrics = Metrics {
+    cell_width: (PAGE_WIDTH - 2.0 * MARGIN) / 7.0,
+    cell_height: 104.0,
+    title_size: 44.0,
+    day_size: 30.0,
+    text_size: 12.0,
+    padding: 10.0,
+    moon_radius: 8.0,
+    labels: true,
+};
+
+const COMPACT: Metrics = Metrics {
+    cell_width: 34.0,
+    cell_height: 30.0,
+    title_size: 18.0,
+    day_size: 12.0,
+    text_size: 7.0,
+    padding: 3.0,
+    moon_radius: 2.5,
+    labels: false,
+};
+
+/// The pages of a jantri as SVG documents, one for each month or one for
+/// the year as chosen by [`JantriOptions::layout`].
+///
+/// # Errors
+/// Returns [`NanakshahiError::YearOutOfRange`] if the year cannot be
+/// converted.
+///
+/// # Examples
+/// ```
+/// use nanakshahi::jantri::{self, JantriOptions, Layout};
+///
+/// let pages = jantri::svg(557, &JantriOptions::default())?;
+/// assert_eq!(pages.len(), 12);
+/// assert!(pages[9].starts_with("<svg xmlns=\"http://www.w3.org/2000/svg\""));
+/// assert!(pages[9].contains("ਪੋਹ ੫੫੭"));
+/// assert!(pages[9].contains("Prakash Guru Gobind Singh Ji"));
+///
+/// let options = JantriOptions {
+///     layout: Layout::OnePage,
+///     ..JantriOptions::default()
+/// };
+/// assert_eq!(jantri::svg(557, &options)?.len(), 1);
+/// # Ok::<(), nanakshahi::NanakshahiError>(())
+/// ```
+pub fn svg(year: i32, options: &JantriOptions) -> Result<Vec<String>, NanakshahiError> {
+    let months: Vec<Month> = NanakshahiMonth::ALL
+        .iter()
+        .map(|&month| {
+            Ok(Month {
+                month,
+                first: options.variant.from(year, month.number(), 1)?,
+                days: options.variant.days_in_month(year, month.number())?,
+            })
+        })
+        .collect::<Result<_, NanakshahiError>>()?;
+    let last: &Month = &months[11];
+    let range: RangeInclusive<NaiveDate> =
+        months[0].first..=last.first + Duration::days(last.days as i64 - 1);
+    let marks: BTreeMap<NaiveDate, Marks> = marks(year, options, range)?;
+
+    Ok(match options.layout {
+        Layout::Monthly => months
+            .iter()
+            .map(|month| month_page(year, month, &marks, options))
+            .collect(),
+        Layout::OnePage => vec![year_page(year, &months, &marks, options)],
+    })
+}
+
+/// A jantri as an HTML document with its pages inline, each printed on a
+/// page of its own.
+///
+/// # Errors
+/// See [`svg`].
+pub fn html(year: i32, options: &JantriOptions) -> Result<String, NanakshahiError> {
+    let pages: Vec<String> = svg(year, options)?;
+    let theme: &Theme = &options.theme;
+    let year: NanakshahiDate = NanakshahiDate::new(year, NanakshahiMonth::Chet, 1)?;
+
+    let mut output: String = String::new();
+    output.push_str("<!DOCTYPE html>\n<html lang=\"pa\">\n<head>\n<meta charset=\"utf-8\">\n");
+    output.push_str(&format!(
+        "<title>{} / {}</title>\n",
+        escape(
+            &year
+                .format_in("ਨਾਨਕਸ਼ਾਹੀ ਜੰਤਰੀ %Y", Script::Gurmukhi)
+                .to_string()
+        ),
+        escape(&year.format("Nanakshahi Jantri %Y").to_string())
+    ));
+    output.push_str("<style>\n");
+    output.push_str("@page { size: A4; margin: 8mm; }\n");
+    output.push_str(&format!(
+        "body {{ margin: 0; background: {}; }}\n",
+        escape(&theme.background)
+    ));
+    output.push_str(".page { break-after: page; margin: 0 auto; max-width: 210mm; }\n");
+    output.push_str(".page:last-child { break-after: auto; }\n");
+    output.push_str(".page svg { display: block; width: 100%; height: auto; }\n");
+    output.push_str("</style>\n</head>\n<body>\n");
+    for page in pages {
+        output.push_str("<section class=\"page\">\n");
+        output.push_str(&page);
+        output.push_str("</section>\n");
+    }
+    output.push_str("</body>\n</html>\n");
+    Ok(output)
+}
+
+/// What is marked on each day in a range, for a Nanakshahi year under the
+/// options.
+fn marks(
+    year: i32,
+    options: &JantriOptions,
+    range: RangeInclusive<NaiveDate>,
+) -> Result<BTreeMap<NaiveDate, Marks>, NanakshahiError> {
+    let mut marks: BTreeMap<NaiveDate, Marks> = BTreeMap::new();
+    let location: &Location = &options.location;
+    // The months of a sidereal variant, and the lunar dates, do not keep to
+    // the original year, so look in the years either side as well.
+    let out_of_range = NanakshahiError::YearOutOfRange(year);
+    let years: RangeInclusive<i32> =
+        year.checked_sub(1).ok_or(out_of_range)?..=year.checked_add(1).ok_or(out_of_range)?;
+
+    if options.observances {
+        for occurrence in observances::observances_in_year_with(year, options.variant, location)? {
+            let observance = occurrence.observance;
+            if let Some(marks) = mark(&mut marks, &range, occurrence.gregorian) {
+                marks
+                    .observances
+                    .push((observance.name, observance.gurmukhi_name));
+            }
+        }
+        for year in years.clone() {
+            for occurrence in observances::lunar_observances_in_year(year, location)? {
+                let observance = occurrence.observance;
+                if let Some(marks) = mark(&mut marks, &range, occurrence.gregorian) {
+                    marks
+                        .observances
+                        .push((observance.name, observance.gurmukhi_name));
+                }
+            }
+        }
+    }
+
+    if options.sangrand {
+        for year in years.clone() {
+            for month in NanakshahiMonth::ALL {
+                let date: NaiveDate = options.variant.from(year, month.number(), 1)?;
+                if let Some(marks) = mark(&mut marks, &range, date) {
+                    marks.sangrand = true;
+                }
+            }
+        }
+    }
+
+    if options.moon_phases {
+        for year in years {
+            for event in lunar::moon_phases_in(year, &location.offset)? {
+                if let Some(marks) = mark(&mut marks, &range, event.observed_date(location)?) {
+                    marks.phase = Some(event.phase);
+                }
+            }
+        }
+    }
+    Ok(marks)
+}
+
+/// The marks of a day, if it is in the range.
+fn mark<'a>(
+    marks: &'a mut BTreeMap<NaiveDate, Marks>,
+    range: &RangeInclusive<NaiveDate>,
+    date: NaiveDate,
+) -> Option<&'a mut Marks> {
+    range
+        .contains(&date)
+        .then(|| marks.entry(date).or_default())
+}
+
+/// The page of a month, with its observances under the grid.
+fn month_page(
+    year: i32,
+    month: &Month,
+    marks: &BTreeMap<NaiveDate, Marks>,
+    options: &JantriOptions,
+) -> String {
+    let theme: &Theme = &options.theme;
+    let mut body: String = String::new();
+
+    let first: NanakshahiDate = NanakshahiDate {
+        year,
+        month: month.month,
+        day: 1,
+    };
+    let last: NaiveDate = month.first + Duration::days(month.days as i64 - 1);
+    let center: f64 = PAGE_WIDTH / 2.0;
+    text(
+        &mut body,
+        (center, 80.0),
+        FULL.title_size,
+        &theme.heading,
+        &theme.gurmukhi_font,
+        "middle",
+        &first.format_in("%B %Y", Script::Gurmukhi).to_string(),
+    );
+    text(
+        &mut body,
+        (center, 112.0),
+        16.0,
+        &theme.muted,
+        &theme.latin_font,
+        "middle",
+        &format!(
+            "{} · {} – {}",
+            first.format("%B %Y %E"),
+            month.first.format("%-d %B %Y"),
+            last.format("%-d %B %Y")
+        ),
+    );
+
+    let grid_bottom: f64 = grid(&mut body, (MARGIN, 140.0), month, marks, theme, &FULL);
+    let mut y: f64 = grid_bottom + 40.0;
+    for (day, date) in month.dates() {
+        let Some(day_marks) = marks.get(&date) else {
+            continue;
+        };
+        for &(name, gurmukhi_name) in &day_marks.observances {
+            observance(
+                &mut body,
+                (MARGIN, y),
+                Some(PAGE_WIDTH - 2.0 * MARGIN),
+                &format!(
+                    "{} {}",
+                    Script::Gurmukhi.numeral(day as u64),
+                    month.month.name_in(Script::Gurmukhi)
+                ),
+                date,
+                (name, gurmukhi_name),
+                theme,
+                15.0,
+            );
+            y += 40.0;
+        }
+    }
+
+    let height: f64 = PAGE_HEIGHT.max(y + 50.0);
+    key(&mut body, (MARGIN, height - MARGIN), options, 12.0);
+    document(PAGE_WIDTH, height, theme, &body)
+}
+
+/// The page of a whole year, with every observance under the grids.
+fn year_page(
+    year: i32,
+    months: &[Month],
+    marks: &BTreeMap<NaiveDate, Marks>,
+    options: &JantriOptions,
+) -> String {
+    let theme: &Theme = &options.theme;
+    let mut body: String = String::new();
+
+    let first: NanakshahiDate = NanakshahiDate {
+        year,
+        month: NanakshahiMonth::Chet,
+        day: 1,
+    };
+    let center: f64 = PAGE_WIDTH / 2.0;
+    text(
+        &mut body,
+        (center, 70.0),
+        36.0,
+        &theme.heading,
+        &theme.gurmukhi_font,
+        "middle",
+        &first
+            .format_in("ਨਾਨਕਸ਼ਾਹੀ ਜੰਤਰੀ %Y", Script::Gurmukhi)
+            .to_string(),
+    );
+    text(
+        &mut body,
+        (center, 98.0),
+        15.0,
+        &theme.muted,
+        &theme.latin_font,
+        "middle",
+        &first.format("Nanakshahi Jantri %Y %E").to_string(),
+    );
+
+    let block_width: f64 = 7.0 * COMPACT.cell_width;
+    let gap: f64 = (PAGE_WIDTH - 2.0 * MARGIN - MONTHS_PER_ROW as f64 * block_width)
+        / (MONTHS_PER_ROW - 1) as f64;
+    let mut y: f64 = 120.0;
+    for row in months.chunks(MONTHS_PER_ROW) {
+        let mut bottom: f64 = y;
+        for (index, month) in row.iter().enumerate() {
+            let x: f64 = MARGIN + index as f64 * (block_width + gap);
+            text(
+                &mut body,
+                (x + block_width / 2.0, y + COMPACT.title_size),
+                COMPACT.title_size,
+                &theme.heading,
+                &theme.gurmukhi_font,
+                "middle",
+                month.month.name_in(Script::Gurmukhi),
+            );
+            text(
+                &mut body,
+                (x + block_width / 2.0, y + COMPACT.title_size + 14.0),
+                9.0,
+                &theme.muted,
+                &theme.latin_font,
+                "middle",
+                month.month.name(),
+            );
+            let top: f64 = y + COMPACT.title_size + 22.0;
+            bottom = bottom.max(grid(&mut body, (x, top), month, marks, theme, &COMPACT));
+        }
+        y = bottom + 16.0;
+    }
+
+    // The observances of the year, in columns.
+    let entries: Vec<(String, NaiveDate, (&str, &str))> = months
+        .iter()
+        .flat_map(|month| {
+            month.dates().flat_map(move |(day, date)| {
+                let label: String = format!(
+                    "{} {}",
+                    Script::Gurmukhi.numeral(day as u64),
+                    month.month.name_in(Script::Gurmukhi)
+                );
+                marks
+                    .get(&date)
+                    .into_iter()
+                    .flat_map(|marks| marks.observances.iter())
+                    .map(move |&names| (label.clone(), date, names))
+            })
+        })
+        .collect();
+    let rows: usize = entries.len().div_ceil(MONTHS_PER_ROW);
+    let top: f64 = y + 20.0;
+    for (index, (label, date, names)) in entries.iter().enumerate() {
+        let x: f64 = MARGIN + (index / rows) as f64 * (block_width + gap);
+        let entry_y: f64 = top + (index % rows) as f64 * 36.0;
+        observance(
+            &mut body,
+            (x, entry_y),
+            None,
+            label,
+            *date,
+            *names,
+            theme,
+            10.0,
+        );
+    }
+
+    let height: f64 = PAGE_HEIGHT.max(top + rows as f64 * 36.0 + 50.0);
+    key(&mut body, (MARGIN, height - MARGIN), options, 10.0);
+    document(PAGE_WIDTH, height, theme, &body)
+}
+
+/// Draw the weekday names and the weeks of a month, returning the bottom of
+/// the grid.
+fn grid(
+    output: &mut String,
+    (x, y): (f64, f64),
+    month: &Month,
+    marks: &BTreeMap<NaiveDate, Marks>,
+    theme: &Theme,
+    metrics: &Metrics,
+) -> f64 {
+    let (width, height): (f64, f64) = (metrics.cell_width, metrics.cell_height);
+    let header: f64 = if metrics.labels { 44.0 } else { 12.0 };
+    for weekday in NanakshahiWeekday::ALL {
+        let center: f64 = x + (weekday.num_days_from_aitvar() as f64 + 0.5) * width;
+        if metrics.labels {
+            text(
+                output,
+                (center, y + 18.0),
+                16.0,
+                &theme.heading,
+                &theme.gurmukhi_font,
+                "middle",
+                weekday.name_in(Script::Gurmukhi),
+            );
+            text(
+                output,
+                (center, y + 34.0),
+                metrics.text_size,
+                &theme.muted,
+                &theme.latin_font,
+                "middle",
+                weekday.english_name(),
+            );
+        } else {
+            text(
+                output,
+                (center, y + 8.0),
+                metrics.text_size,
+                &theme.heading,
+                &theme.gurmukhi_font,
+                "middle",
+                short_weekday_name(weekday, Script::Gurmukhi),
+            );
+        }
+    }
+
+    let top: f64 = y + header;
+    let offset: u32 = month.first.weekday().num_days_from_sunday();
+    let empty: Marks = Marks::default();
+    for (day, date) in month.dates() {
+        let index: u32 = offset + day as u32 - 1;
+        let cell: (f64, f64) = (
+            x + (index % 7) as f64 * width,
+            top + (index / 7) as f64 * height,
+        );
+        let day_marks: &Marks = marks.get(&date).unwrap_or(&empty);
+        day_cell(output, cell, day, date, day_marks, theme, metrics);
+    }
+    top + month.weeks() as f64 * height
+}
+
+/// Draw a day of a month grid.
+fn day_cell(
+    output: &mut String,
+    (x, y): (f64, f64),
+    day: u8,
+    date: NaiveDate,
+    marks: &Marks,
+    theme: &Theme,
+    metrics: &Metrics,
+) {
+    let (width, height): (f64, f64) = (metrics.cell_width, metrics.cell_height);
+    let observance: bool = !marks.observances.is_empty();
+    let fill: &str = if observance {
+        &theme.highlight
+    } else {
+        &theme.background
+    };
+    output.push_str(&format!(
+        "<rect x=\"{x}\" y=\"{y}\" width=\"{width}\" height=\"{height}\" fill=\"{}\" stroke=\"{}\" stroke-width=\"0.5\"/>\n",
+        escape(fill),
+        escape(&theme.grid)
+    ));
+    if marks.sangrand {
+        output.push_str(&format!(
+            "<rect x=\"{x}\" y=\"{y}\" width=\"{width}\" height=\"{}\" fill=\"{}\"/>\n",
+            height / 16.0,
+            escape(&theme.heading)
+        ));
+    }
+
+    let pad: f64 = metrics.padding;
+    let color: &str = if observance {
+        &theme.accent
+    } else {
+        &theme.text
+    };
+    text(
+        output,
+        (x + pad, y + pad + metrics.day_size),
+        metrics.day_size,
+        color,
+        &theme.gurmukhi_font,
+        "start",
+        &Script::Gurmukhi.numeral(day as u64),
+    );
+    let gregorian: String = if day == 1 || date.day() == 1 {
+        date.format("%-d %b").to_string()
+    } else {
+        date.day().to_string()
+    };
+    text(
+        output,
+        (x + width - pad, y + pad + metrics.text_size),
+        metrics.text_size,
+        &theme.muted,
+        &theme.latin_font,
+        "end",
+        &gregorian,
+    );
+
+    let radius: f64 = metrics.moon_radius;
+    let mut labels: Vec<String> = Vec::new();
+    if let Some(phase) = marks.phase {
+        let fill: &str = match phase {
+            MoonPhase::Masya => &theme.text,
+            MoonPhase::Puranmashi => &theme.background,
+        };
+        moon(
+            output,
+            (x + width - pad - radius, y + height - pad - radius),
+            radius,
+            fill,
+            &theme.text,
+        );
+        labels.push(phase.name_in(Script::Gurmukhi));
+    }
+    if marks.sangrand {
+        labels.push("ਸੰਗਰਾਂਦ".to_string());
+    }
+    if metrics.labels {
+        for (index, label) in labels.iter().enumerate() {
+            text(
+                output,
+                (x + pad, y + height - pad - index as f64 * 16.0),
+                metrics.text_size,
+                &theme.heading,
+                &theme.gurmukhi_font,
+                "start",
+                label,
+            );
+        }
+    }
+}
+
+/// Draw an observance with its date, Gurmukhi name and English name.
+///
+/// Given the width of a row, the names follow the label with the Gregorian
+/// date at the end of the row. Without it, the date follows the label and
+/// the names are stacked under them, for narrow columns.
+#[allow(clippy::too_many_arguments)]
+fn observance(
+    output: &mut String,
+    (x, y): (f64, f64),
+    width: Option<f64>,
+    label: &str,
+    date: NaiveDate,
+    (name, gurmukhi_name): (&str, &str),
+    theme: &Theme,
+    size: f64,
+) {
+    let indent: f64 = size * 5.0;
+    let (date_position, anchor, names): ((f64, f64), &str, (f64, f64)) = match width {
+        Some(width) => ((x + width, y), "end", (x + indent, y)),
+        None => ((x + indent, y), "start", (x, y + size * 1.3)),
+    };
+    text(
+        output,
+        (x, y),
+        size,
+        &theme.accent,
+        &theme.gurmukhi_font,
+        "start",
+        label,
+    );
+    text(
+        output,
+        date_position,
+        size * 0.8,
+        &theme.muted,
+        &theme.latin_font,
+        anchor,
+        &date.format("%a %-d %b %Y").to_string(),
+    );
+    text(
+        output,
+        names,
+        size,
+        &theme.text,
+        &theme.gurmukhi_font,
+        "start",
+        gurmukhi_name,
+    );
+    text(
+        output,
+        (names.0, names.1 + size * 1.2),
+        size * 0.8,
+        &theme.muted,
+        &theme.latin_font,
+        "start",
+        name,
+    );
+}
+
+/// Draw the explanation of the marks that are shown.
+fn key(output: &mut String, (x, y): (f64, f64), options: &JantriOptions, size: f64) {
+    let theme: &Theme = &options.theme;
+    let mut x: f64 = x;
+    let entry = |output: &mut String, x: &mut f64, label: String| {
+        text(
+            output,
+            (*x + size * 1.5, y),
+            size,
+            &theme.text,
+            &theme.gurmukhi_font,
+            "start",
+            &label,
+        );
+        *x += size * 16.0;
+    };
+
+    if options.moon_phases {
+        for phase in [MoonPhase::Puranmashi, MoonPhase::Masya] {
+            let fill: &str = match phase {
+                MoonPhase::Masya => &theme.text,
+                MoonPhase::Puranmashi => &theme.background,
+            };
+            moon(
+                output,
+                (x + size / 2.0, y - size * 0.35),
+                size / 2.0,
+                fill,
+                &theme.text,
+            );
+            let label: String = format!(
+                "{} / {}",
+                phase.name_in(Script::Gurmukhi),
+                phase.name_in(Script::Latin)
+            );
+            entry(output, &mut x, label);
+        }
+    }
+    if options.sangrand {
+        output.push_str(&format!(
+            "<rect x=\"{x}\" y=\"{}\" width=\"{size}\" height=\"{}\" fill=\"{}\"/>\n",
+            y - size * 0.6,
+            size / 4.0,
+            escape(&theme.heading)
+        ));
+        entry(output, &mut x, "ਸੰਗਰਾਂਦ / Sangrand".to_string());
+    }
+    if options.observances {
+        output.push_str(&format!(
+            "<rect x=\"{x}\" y=\"{}\" width=\"{size}\" height=\"{size}\" fill=\"{}\" stroke=\"{}\" stroke-width=\"0.5\"/>\n",
+            y - size * 0.85,
+            escape(&theme.highlight),
+            escape(&theme.grid)
+        ));
+        entry(output, &mut x, "ਗੁਰਪੁਰਬ / Gurpurab".to_string());
+    }
+}
+
+fn moon(output: &mut String, (x, y): (f64, f64), radius: f64, fill: &str, stroke: &str) {
+    output.push_str(&format!(
+        "<circle cx=\"{x}\" cy=\"{y}\" r=\"{radius}\" fill=\"{}\" stroke=\"{}\" stroke-width=\"1\"/>\n",
+        escape(fill),
+        escape(stroke)
+    ));
+}
+
+fn text(
+    output: &mut String,
+    (x, y): (f64, f64),
+    size: f64,
+    fill: &str,
+    font: &str,
+    anchor: &str,
+    content: &str,
+) {
+    output.push_str(&format!(
+        "<text x=\"{x}\" y=\"{y}\" font-size=\"{size}\" fill=\"{}\" font-family=\"{}\" text-anchor=\"{anchor}\">{}</text>\n",
+        escape(fill),
+        escape(font),
+        escape(content)
+    ));
+}
+
+/// An SVG document of a page with the given content.
+fn document(width: f64, height: f64, theme: &Theme, body: &str) -> String {
+    format!(
+        concat!(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{width}\" height=\"{height}\" ",
+            "viewBox=\"0 0 {width} {height}\">\n",
+            "<rect width=\"100%\" height=\"100%\" fill=\"{background}\"/>\n",
+            "{body}</svg>\n"
+        ),
+        width = width,
+        height = height,
+        background = escape(&theme.background),
+        body = body
+    )
+}
+
+/// Escape text for XML content and attribute values.
+fn escape(text: &str) -> String {
+    let mut escaped: String = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_month_pages() {
+        let pages = svg(557, &JantriOptions::default()).unwrap();
+        let poh = &pages[9];
+
+        assert_eq!(pages.len(), 12);
+        assert!(poh.contains(">ਪੋਹ ੫੫੭</text>"));
+        assert!(poh.contains(">Poh 557 NS · 14 December 2025 – 12 January 2026</text>"));
+        assert!(poh.contains(">੨੩ ਪੋਹ</text>"));
+        assert!(poh.contains(">ਪ੍ਰਕਾਸ਼ ਗੁਰੂ ਗੋਬਿੰਦ ਸਿੰਘ ਜੀ</text>"));
+        assert!(poh.contains(">Mon 5 Jan 2026</text>"));
+        assert!(poh.contains(">ਪੂਰਨਮਾਸ਼ੀ</text>"));
+        assert!(poh.contains(">ਮੱਸਿਆ</text>"));
+        // Poh begins on Aitvar, so its first day is at the corner of the grid.
+        assert!(poh.contains(&format!(
+            "<rect x=\"{MARGIN}\" y=\"184\" width=\"{}\" height=\"104\"",
+            FULL.cell_width
+        )));
+    }
+
+    #[test]
+    fn test_one_page() {
+        let options = JantriOptions {
+            variant: CalendarVariant::Amended2010,
+            layout: Layout::OnePage,
+            ..JantriOptions::default()
+        };
+        let pages = svg(557, &options).unwrap();
+
+        assert_eq!(pages.len(), 1);
+        for month in NanakshahiMonth::ALL {
+            assert!(pages[0].contains(&format!(">{}</text>", month.name())));
+        }
+        assert!(pages[0].contains(">Shaheedi Guru Arjan Dev Ji</text>"));
+        assert_eq!(
+            pages[0].matches(">ਵੈਸਾਖੀ (ਖ਼ਾਲਸਾ ਸਾਜਨਾ ਦਿਵਸ)</text>").count(),
+            1
+        );
+    }
+
+    #[test]
+    fn test_sangrand_bar() {
+        for variant in CalendarVariant::ALL {
+            let options = JantriOptions {
+                variant,
+                ..JantriOptions::default()
+            };
+            let pages = svg(557, &options).unwrap();
+            for (page, month) in pages.iter().zip(NanakshahiMonth::ALL) {
+                // The bar along the top of the first day's cell, in the
+                // first row of the grid.
+                let first: NaiveDate = variant.from(557, month.number(), 1).unwrap();
+                let offset: u32 = first.weekday().num_days_from_sunday();
+                let bar: String = format!(
+                    "<rect x=\"{}\" y=\"184\" width=\"{}\" height=\"{}\" fill=",
+                    MARGIN + offset as f64 * FULL.cell_width,
+                    FULL.cell_width,
+                    FULL.cell_height / 16.0
+                );
+                assert_eq!(page.matches(&bar).count(), 1, "{variant:?} {month}");
+                assert_eq!(
+                    page.matches(&format!("height=\"{}\"", FULL.cell_height / 16.0))
+                        .count(),
+                    1,
+                    "{variant:?} {month}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_theme_and_html() {
+        let options = JantriOptions {
+            theme: Theme {
+                accent: "crimson".to_string(),
+                gurmukhi_font: "\"Anmol Lipi\"".to_string(),
+                ..Theme::default()
+            },
+            moon_phases: false,
+            ..JantriOptions::default()
+        };
+        let html = html(557, &options).unwrap();
+
+        assert!(html.starts_with("<!DOCTYPE html>\n"));
+        assert!(html.contains("<title>ਨਾਨਕਸ਼ਾਹੀ ਜੰਤਰੀ ੫੫੭ / Nanakshahi Jantri 557</title>"));
+        assert_eq!(html.matches("<section class=\"page\">").count(), 12);
+        assert!(html.contains("fill=\"crimson\""));
+        assert!(html.contains("font-family=\"&quot;Anmol Lipi&quot;\""));
+        assert!(!html.contains("ਪੂਰਨਮਾਸ਼ੀ"));
+    }
+}
@@ -0,0 +1,269 @@
+//! Conversion of Julian calendar dates, as cited in sources from the Guru
+//! period.
+//!
+//! [`crate::to`] reads its input as a proleptic Gregorian date. Sources from
+//! before the Gregorian reform give dates in the Julian calendar, which by
+//! 1469 ran nine days behind. The functions here convert such dates, either
+//! as pure Julian dates or as historical dates that switch from the Julian to
+//! the Gregorian calendar on a chosen [`Switchover`].
+//!
+//! Years are numbered astronomically and start on 1 January, so dates
+//! recorded with a 25 March new year must be adjusted first.
+
+use chrono::{Datelike, NaiveDate};
+
+use crate::{NanakshahiDate, NanakshahiError, JULIAN_DAY_OF_COMMON_ERA};
+
+/// The date on which the Gregorian calendar replaced the Julian calendar.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Switchover {
+    first_gregorian_day: NaiveDate,
+}
+
+impl Switchover {
+    /// The original reform, where 4 October 1582 was followed by 15 October.
+    pub const GREGORIAN: Switchover = Switchover {
+        first_gregorian_day: NaiveDate::from_ymd_opt(1582, 10, 15).expect("Invalid date"),
+    };
+
+    /// The switch in Great Britain and its colonies, including British India,
+    /// where 2 September 1752 was followed by 14 September.
+    pub const BRITISH: Switchover = Switchover {
+        first_gregorian_day: NaiveDate::from_ymd_opt(1752, 9, 14).expect("Invalid date"),
+    };
+
+    /// A switchover on the given Gregorian date, which is the first day
+    /// reckoned in the Gregorian calendar.
+    pub fn new(first_gregorian_day: NaiveDate) -> Self {
+        Switchover {
+            first_gregorian_day,
+        }
+    }
+
+    /// The first day reckoned in the Gregorian calendar.
+    pub fn first_gregorian_day(&self) -> NaiveDate {
+        self.first_gregorian_day
+    }
+}
+
+impl Default for Switchover {
+    fn default() -> Self {
+        Switchover::GREGORIAN
+    }
+}
+
+/// Whether a year is a leap year in the Julian calendar.
+pub fn is_leap_year(year: i32) -> bool {
+    year.rem_euclid(4) == 0
+}
+
+/// Convert a Julian date to the proleptic Gregorian calendar.
+///
+/// # Errors
+/// Returns [`NanakshahiError::InvalidJulianDate`] if the Julian date does not
+/// exist or cannot be represented by chrono.
+///
+/// # Examples
+/// ```
+/// use chrono::NaiveDate;
+///
+/// // Guru Nanak's birth, as recorded in the Julian calendar.
+/// let date = nanakshahi::julian::to_gregorian(1469, 4, 15)?;
+/// assert_eq!(date, NaiveDate::from_ymd_opt(1469, 4, 24).unwrap());
+/// # Ok::<(), nanakshahi::NanakshahiError>(())
+/// ```
+pub fn to_gregorian(year: i32, month: u8, day: u8) -> Result<NaiveDate, NanakshahiError> {
+    let invalid = NanakshahiError::InvalidJulianDate { year, month, day };
+    if !(1..=12).contains(&month) || day == 0 || day > days_in_month(year, month) {
+        return Err(invalid);
+    }
+
+    let a: i64 = (14 - month as i64) / 12;
+    let y: i64 = year as i64 + 4800 - a;
+    let m: i64 = month as i64 + 12 * a - 3;
+    let julian_day: i64 = day as i64 + (153 * m + 2) / 5 + 365 * y + y.div_euclid(4) - 32083;
+
+    i32::try_from(julian_day - JULIAN_DAY_OF_COMMON_ERA)
+        .ok()
+        .and_then(NaiveDate::from_num_days_from_ce_opt)
+        .ok_or(invalid)
+}
+
+/// Convert a proleptic Gregorian date to a Julian `(year, month, day)`.
+///
+/// # Examples
+/// ```
+/// use chrono::NaiveDate;
+///
+/// let date = NaiveDate::from_ymd_opt(1752, 9, 14).unwrap();
+/// assert_eq!(nanakshahi::julian::from_gregorian(date), (1752, 9, 3));
+/// ```
+pub fn from_gregorian(date: NaiveDate) -> (i32, u8, u8) {
+    let julian_day: i64 = date.num_days_from_ce() as i64 + JULIAN_DAY_OF_COMMON_ERA;
+
+    let c: i64 = julian_day + 32082;
+    let d: i64 = (4 * c + 3).div_euclid(1461);
+    let e: i64 = c - (1461 * d).div_euclid(4);
+    let m: i64 = (5 * e + 2) / 153;
+
+    let day: i64 = e - (153 * m + 2) / 5 + 1;
+    let month: i64 = m + 3 - 12 * (m / 10);
+    let year: i64 = d - 4800 + m / 10;
+    (year as i32, month as u8, day as u8)
+}
+
+/// Convert a historical date to the proleptic Gregorian calendar, reading it
+/// as Julian before the switchover and as Gregorian from it onwards.
+///
+/// # Errors
+/// Returns [`NanakshahiError::InvalidJulianDate`] if a date before the
+/// switchover does not exist, including the days skipped at the switchover,
+/// or [`NanakshahiError::InvalidGregorianDate`] if a later date does not.
+///
+/// # Examples
+/// ```
+/// use chrono::NaiveDate;
+/// use nanakshahi::julian::{self, Switchover};
+///
+/// let date = julian::historical_to_gregorian(1752, 9, 2, Switchover::BRITISH)?;
+/// assert_eq!(date, NaiveDate::from_ymd_opt(1752, 9, 13).unwrap());
+/// assert!(julian::historical_to_gregorian(1752, 9, 3, Switchover::BRITISH).is_err());
+/// # Ok::<(), nanakshahi::NanakshahiError>(())
+/// ```
+pub fn historical_to_gregorian(
+    year: i32,
+    month: u8,
+    day: u8,
+    switchover: Switchover,
+) -> Result<NaiveDate, NanakshahiError> {
+    let first: NaiveDate = switchover.first_gregorian_day;
+    if (year, month as u32, day as u32) >= (first.year(), first.month(), first.day()) {
+        return NaiveDate::from_ymd_opt(year, month as u32, day as u32)
+            .ok_or(NanakshahiError::InvalidGregorianDate { year, month, day });
+    }
+
+    let date: NaiveDate = to_gregorian(year, month, day)?;
+    if date >= first {
+        return Err(NanakshahiError::InvalidJulianDate { year, month, day });
+    }
+    Ok(date)
+}
+
+/// Convert a Julian date to a Nanakshahi date.
+///
+/// # Errors
+/// Returns [`NanakshahiError::InvalidJulianDate`] if the Julian date does not
+/// exist.
+///
+/// # Examples
+/// ```
+/// use nanakshahi::{NanakshahiDate, NanakshahiMonth};
+///
+/// let date = nanakshahi::julian::to(1469, 4, 15)?;
+/// assert_eq!(date, NanakshahiDate::new(1, NanakshahiMonth::Vaisakh, 11)?);
+/// # Ok::<(), nanakshahi::NanakshahiError>(())
+/// ```
+pub fn to(year: i32, month: u8, day: u8) -> Result<NanakshahiDate, NanakshahiError> {
+    from_naive(to_gregorian(year, month, day)?)
+}
+
+/// Convert a historical date to a Nanakshahi date, reading it as Julian
+/// before the switchover and as Gregorian from it onwards.
+///
+/// # Errors
+/// See [`historical_to_gregorian`].
+pub fn to_historical(
+    year: i32,
+    month: u8,
+    day: u8,
+    switchover: Switchover,
+) -> Result<NanakshahiDate, NanakshahiError> {
+    from_naive(historical_to_gregorian(year, month, day, switchover)?)
+}
+
+/// Convert a Nanakshahi date to a Julian `(year, month, day)`.
+///
+/// # Errors
+/// Returns the same errors as [`crate::from`].
+pub fn from(year: i32, month: u8, day: u8) -> Result<(i32, u8, u8), NanakshahiError> {
+    Ok(from_gregorian(crate::from(year, month, day)?))
+}
+
+fn from_naive(date: NaiveDate) -> Result<NanakshahiDate, NanakshahiError> {
+    crate::to(date.year(), date.month() as u8, date.day() as u8)
+}
+
+fn days_in_month(year: i32, month: u8) -> u8 {
+    match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::NanakshahiMonth;
+
+    fn naive(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn test_to_gregorian() {
+        assert_eq!(to_gregorian(1582, 10, 4), Ok(naive(1582, 10, 14)));
+        assert_eq!(to_gregorian(1900, 2, 29), Ok(naive(1900, 3, 13)));
+        assert_eq!(to_gregorian(1, 1, 1), Ok(naive(0, 12, 30)));
+        assert_eq!(to_gregorian(-100, 3, 1), Ok(naive(-100, 2, 27)));
+        assert_eq!(
+            to_gregorian(1469, 2, 30),
+            Err(NanakshahiError::InvalidJulianDate {
+                year: 1469,
+                month: 2,
+                day: 30
+            })
+        );
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let mut date = naive(-500, 1, 1);
+        while date < naive(2100, 1, 1) {
+            let (year, month, day) = from_gregorian(date);
+            assert_eq!(to_gregorian(year, month, day), Ok(date));
+            date += chrono::Duration::days(17);
+        }
+    }
+
+    #[test]
+    fn test_historical_to_gregorian() {
+        let switchover = Switchover::GREGORIAN;
+
+        assert_eq!(
+            historical_to_gregorian(1582, 10, 4, switchover),
+            Ok(naive(1582, 10, 14))
+        );
+        assert_eq!(
+            historical_to_gregorian(1582, 10, 15, switchover),
+            Ok(naive(1582, 10, 15))
+        );
+        assert!(historical_to_gregorian(1582, 10, 10, switchover).is_err());
+        assert_eq!(
+            historical_to_gregorian(1700, 2, 29, Switchover::BRITISH),
+            Ok(naive(1700, 3, 11))
+        );
+        assert!(historical_to_gregorian(1700, 2, 29, switchover).is_err());
+    }
+
+    #[test]
+    fn test_to_nanakshahi() {
+        // Guru Gobind Singh's Joti Jot, 7 October 1708 in the Julian calendar.
+        assert_eq!(
+            to(1708, 10, 7),
+            NanakshahiDate::new(240, NanakshahiMonth::Kattak, 4)
+        );
+        assert_eq!(from(240, 8, 4), Ok((1708, 10, 7)));
+    }
+}
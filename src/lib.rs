@@ -1,144 +1,372 @@
-use chrono::{Datelike, Duration, NaiveDate};
+use chrono::{Datelike, NaiveDate};
 
-const EPOCH_BEFORE_MID_MARCH: u16 = 1469;
-const EPOCH_ON_OR_AFTER_MID_MARCH: u16 = 1468;
+mod astro;
+pub mod bikrami;
+pub mod cal;
+mod date;
+mod datelike;
+mod error;
+pub mod format;
+pub mod ics;
+pub mod jantri;
+pub mod julian;
+mod location;
+pub mod lunar;
+mod month;
+pub mod observances;
+mod parse;
+pub mod sangrand;
+mod script;
+#[cfg(feature = "serde")]
+pub mod serde;
+mod sidereal;
+pub mod solar;
+mod variant;
+mod weekday;
+
+pub use date::{DayBoundary, NanakshahiDate};
+pub use datelike::NanakshahiDatelike;
+pub use error::{NanakshahiError, ParseError, ParseErrorKind};
+pub use location::Location;
+pub use month::NanakshahiMonth;
+pub use script::Script;
+pub use sidereal::{Ayanamsa, Rashi};
+pub use variant::{CalendarVariant, VariantDate};
+pub use weekday::NanakshahiWeekday;
+
+const EPOCH_BEFORE_MID_MARCH: i32 = 1469;
+const EPOCH_ON_OR_AFTER_MID_MARCH: i32 = 1468;
+/// Years between the Gregorian calendar and the Bikrami Samvat that begins
+/// in its spring.
+const SAMVAT_OFFSET: i32 = 57;
+/// The Rata Die of 1 Chet 0, which was 14 March 1468.
+const RATA_DIE_OF_EPOCH: i64 = 535884;
+/// Days between the Julian Day Number and the Rata Die, which is also
+/// chrono's count of days from the common era.
+const JULIAN_DAY_OF_COMMON_ERA: i64 = 1721425;
 const NANAKSHAHI_DAYS_IN_MONTHS: [i32; 12] = [31, 31, 31, 31, 31, 30, 30, 30, 30, 30, 30, 30];
-const NANAKSHAHI_MONTH_NAMES: [&'static str; 12] = [
+const NANAKSHAHI_MONTH_NAMES: [&str; 12] = [
     "Chet", "Vaisakh", "Jeth", "Harh", "Sawan", "Bhadon", "Assu", "Kattak", "Maghar", "Poh",
     "Magh", "Phaggan",
 ];
-const GREGORIAN_MONTH_NAMES: [&'static str; 12] = [
-    "January",
-    "February",
-    "March",
-    "April",
-    "May",
-    "June",
-    "July",
-    "August",
-    "September",
-    "October",
-    "November",
-    "December",
+const NANAKSHAHI_MONTH_NAMES_GURMUKHI: [&str; 12] = [
+    "ਚੇਤ",
+    "ਵੈਸਾਖ",
+    "ਜੇਠ",
+    "ਹਾੜ",
+    "ਸਾਵਣ",
+    "ਭਾਦੋਂ",
+    "ਅੱਸੂ",
+    "ਕੱਤਕ",
+    "ਮੱਘਰ",
+    "ਪੋਹ",
+    "ਮਾਘ",
+    "ਫੱਗਣ",
+];
+const NANAKSHAHI_MONTH_NAMES_SHAHMUKHI: [&str; 12] = [
+    "چیت",
+    "ویساکھ",
+    "جیٹھ",
+    "ہاڑ",
+    "ساون",
+    "بھادوں",
+    "اسو",
+    "کتک",
+    "مگھر",
+    "پوہ",
+    "ماگھ",
+    "پھگن",
 ];
+const NANAKSHAHI_MONTH_NAMES_DEVANAGARI: [&str; 12] = [
+    "चेत",
+    "वैसाख",
+    "जेठ",
+    "हाड़",
+    "सावण",
+    "भादों",
+    "अस्सू",
+    "कत्तक",
+    "मग्घर",
+    "पोह",
+    "माघ",
+    "फग्गण",
+];
+
+/// Whether a Nanakshahi year is a leap year.
+///
+/// Phaggan, the last month of the year, spans the end of February. A
+/// Nanakshahi year is therefore a leap year, with a 31-day Phaggan, when the
+/// Gregorian year in which it ends is a leap year.
+///
+/// # Examples
+/// ```
+/// assert!(nanakshahi::is_leap_year(555)); // Ends on 13 March 2024
+/// assert!(!nanakshahi::is_leap_year(556)); // Ends on 13 March 2025
+/// assert!(nanakshahi::is_leap_year(-1)); // Ends on 13 March 1468
+/// ```
+pub fn is_leap_year(year: i32) -> bool {
+    let year: i64 = year as i64 + EPOCH_BEFORE_MID_MARCH as i64;
+    (year % 4 == 0 && year % 100 != 0) || year % 400 == 0
+}
 
-pub struct Date {
-    pub year: u16,
-    pub month: &'static str,
-    pub day: u8,
+/// Number of days in a month of a Nanakshahi year.
+///
+/// # Errors
+/// Returns [`NanakshahiError::InvalidMonth`] if `month` is not between 1 and
+/// 12.
+///
+/// # Examples
+/// ```
+/// assert_eq!(nanakshahi::days_in_month(557, 1), Ok(31));
+/// assert_eq!(nanakshahi::days_in_month(555, 12), Ok(31));
+/// assert_eq!(nanakshahi::days_in_month(556, 12), Ok(30));
+/// ```
+pub fn days_in_month(year: i32, month: u8) -> Result<u8, NanakshahiError> {
+    Ok(NanakshahiMonth::try_from(month)?.days(year))
 }
 
 /// Convert a Nanakshahi date to a Gregorian date.
 ///
+/// Years are numbered astronomically: year 0 began on 14 March 1468 and the
+/// year before it is -1. Gregorian dates are proleptic, as in chrono.
+///
+/// # Errors
+/// Returns [`NanakshahiError::InvalidMonth`] or
+/// [`NanakshahiError::InvalidDay`] if the Nanakshahi date does not exist, or
+/// [`NanakshahiError::YearOutOfRange`] if the Gregorian date is outside the
+/// range supported by chrono.
+///
 /// # Examples
 /// ```
 /// let year = 535;
 /// let month = 1;
 /// let day = 1;
 ///
-/// let date = nanakshahi::to(year, month, day);
+/// let date = nanakshahi::from(year, month, day)?;
+/// # Ok::<(), nanakshahi::NanakshahiError>(())
 /// ```
-pub fn from(year: u16, month: u8, day: u8) -> Date {
-    let mut offset: i32 = 0;
-    for index in 0..(month - 1) as usize {
-        offset += NANAKSHAHI_DAYS_IN_MONTHS[index];
-    }
-    offset += day as i32 - 1;
-
-    let mut date: NaiveDate =
-        NaiveDate::from_ymd_opt(year as i32 + EPOCH_ON_OR_AFTER_MID_MARCH as i32, 3, 14)
-            .expect("Invalid date");
-    date = date + Duration::days(offset as i64);
+pub fn from(year: i32, month: u8, day: u8) -> Result<NaiveDate, NanakshahiError> {
+    let date: NanakshahiDate = NanakshahiDate::new(year, NanakshahiMonth::try_from(month)?, day)?;
 
-    Date {
-        year: date.year() as u16,
-        month: GREGORIAN_MONTH_NAMES[(date.month0()) as usize],
-        day: date.day() as u8,
-    }
+    i32::try_from(date.to_rata_die())
+        .ok()
+        .and_then(NaiveDate::from_num_days_from_ce_opt)
+        .ok_or(NanakshahiError::YearOutOfRange(year))
 }
 
 /// Convert a Gregorian date to a Nanakshahi date.
 ///
+/// # Errors
+/// Returns [`NanakshahiError::InvalidGregorianDate`] if the Gregorian date
+/// does not exist or is outside the range supported by chrono.
+///
 /// # Examples
 /// ```
 /// let year = 2003;
 /// let month = 3;
 /// let day = 14;
 ///
-/// let date = nanakshahi::to(year, month, day);
+/// let date = nanakshahi::to(year, month, day)?;
+///
+/// // Dates before 14 March 1468 fall in years 0 and earlier.
+/// let date = nanakshahi::to(1400, 1, 1)?;
+/// assert_eq!(date.year, -69);
+/// # Ok::<(), nanakshahi::NanakshahiError>(())
 /// ```
-pub fn to(year: u16, month: u8, day: u8) -> Date {
-    let epoch: u16 = if month > 3 || (month == 3 && day >= 14) {
-        EPOCH_ON_OR_AFTER_MID_MARCH
-    } else {
-        EPOCH_BEFORE_MID_MARCH
-    };
-    let mut offset: i64 = days_between(year, month, day);
-
-    for (index, &days) in NANAKSHAHI_DAYS_IN_MONTHS.iter().enumerate() {
-        if offset < days as i64 {
-            return Date {
-                year: year - epoch,
-                month: NANAKSHAHI_MONTH_NAMES[index],
-                day: (offset + 1) as u8,
-            };
-        } else {
-            offset -= days as i64;
-        }
-    }
-
-    // If we fall through the loop (which should not happen), panic.
-    panic!("Offset exceeded the total number of days in the Nanakshahi year");
+pub fn to(year: i32, month: u8, day: u8) -> Result<NanakshahiDate, NanakshahiError> {
+    NanakshahiDate::from_days(days_between(year, month, day)?)
+        .ok_or(NanakshahiError::InvalidGregorianDate { year, month, day })
 }
 
-fn days_between(year: u16, month: u8, day: u8) -> i64 {
-    let offset: u16 = if month > 3 || (month == 3 && day >= 14) {
-        0
-    } else {
-        1
-    };
-    let date: NaiveDate =
-        NaiveDate::from_ymd_opt(year as i32, month as u32, day as u32).expect("Invalid date");
-    let reference_date: NaiveDate =
-        NaiveDate::from_ymd_opt((year - offset) as i32, 3, 14).expect("Invalid date");
-    (date - reference_date).num_days()
+/// Days from 1 Chet 0 to a Gregorian date.
+fn days_between(year: i32, month: u8, day: u8) -> Result<i64, NanakshahiError> {
+    let date: NaiveDate = NaiveDate::from_ymd_opt(year, month as u32, day as u32)
+        .ok_or(NanakshahiError::InvalidGregorianDate { year, month, day })?;
+    Ok(date.num_days_from_ce() as i64 - RATA_DIE_OF_EPOCH)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use chrono::{Datelike, Duration};
 
     #[test]
     fn test_to_on_mid_march() {
-        let date: Date = to(2025, 3, 14);
+        let date: NanakshahiDate = to(2025, 3, 14).unwrap();
 
         assert_eq!(date.year, 557);
-        assert_eq!(date.month, "Chet");
+        assert_eq!(date.month, NanakshahiMonth::Chet);
         assert_eq!(date.day, 1);
     }
 
     #[test]
     fn test_to_before_mid_march() {
-        let date: Date = to(2025, 3, 13);
+        let date: NanakshahiDate = to(2025, 3, 13).unwrap();
 
         assert_eq!(date.year, 556);
-        assert_eq!(date.month, "Phaggan");
+        assert_eq!(date.month, NanakshahiMonth::Phaggan);
         assert_eq!(date.day, 30);
     }
 
     #[test]
     fn test_from_on_mid_march() {
-        let date = from(557, 1, 1);
-        assert_eq!(date.year, 2025);
-        assert_eq!(date.month, "March");
-        assert_eq!(date.day, 14);
+        let date = from(557, 1, 1).unwrap();
+        assert_eq!(date.year(), 2025);
+        assert_eq!(date.month(), 3);
+        assert_eq!(date.day(), 14);
     }
 
     #[test]
     fn test_from_before_mid_march() {
-        let date = from(556, 12, 30);
-        assert_eq!(date.year, 2025);
-        assert_eq!(date.month, "March");
-        assert_eq!(date.day, 13);
+        let date = from(556, 12, 30).unwrap();
+        assert_eq!(date.year(), 2025);
+        assert_eq!(date.month(), 3);
+        assert_eq!(date.day(), 13);
+    }
+
+    #[test]
+    fn test_is_leap_year() {
+        assert!(is_leap_year(531)); // Ends in 2000
+        assert!(!is_leap_year(431)); // Ends in 1900
+        assert!(is_leap_year(555));
+        assert!(!is_leap_year(556));
+    }
+
+    #[test]
+    fn test_days_in_month() {
+        assert_eq!(days_in_month(556, 5), Ok(31));
+        assert_eq!(days_in_month(556, 6), Ok(30));
+        assert_eq!(days_in_month(556, 12), Ok(30));
+        assert_eq!(days_in_month(555, 12), Ok(31));
+        assert_eq!(days_in_month(556, 0), Err(NanakshahiError::InvalidMonth(0)));
+        assert_eq!(
+            days_in_month(556, 13),
+            Err(NanakshahiError::InvalidMonth(13))
+        );
+    }
+
+    #[test]
+    fn test_to_last_day_of_leap_year() {
+        let date: NanakshahiDate = to(2024, 3, 13).unwrap();
+
+        assert_eq!(date.year, 555);
+        assert_eq!(date.month, NanakshahiMonth::Phaggan);
+        assert_eq!(date.day, 31);
+    }
+
+    #[test]
+    fn test_to_leap_day() {
+        let date: NanakshahiDate = to(2024, 2, 29).unwrap();
+
+        assert_eq!(date.year, 555);
+        assert_eq!(date.month, NanakshahiMonth::Phaggan);
+        assert_eq!(date.day, 18);
+    }
+
+    #[test]
+    fn test_from_last_day_of_leap_year() {
+        let date = from(555, 12, 31).unwrap();
+        assert_eq!(date.year(), 2024);
+        assert_eq!(date.month(), 3);
+        assert_eq!(date.day(), 13);
+    }
+
+    #[test]
+    fn test_from_after_leap_year() {
+        let date = from(556, 1, 1).unwrap();
+        assert_eq!(date.year(), 2024);
+        assert_eq!(date.month(), 3);
+        assert_eq!(date.day(), 14);
+    }
+
+    #[test]
+    fn test_from_invalid_month() {
+        assert_eq!(
+            from(557, 13, 1).err(),
+            Some(NanakshahiError::InvalidMonth(13))
+        );
+    }
+
+    #[test]
+    fn test_from_invalid_day() {
+        assert_eq!(
+            from(556, 12, 31).err(),
+            Some(NanakshahiError::InvalidDay {
+                year: 556,
+                month: 12,
+                day: 31
+            })
+        );
+        assert_eq!(
+            from(557, 7, 0).err(),
+            Some(NanakshahiError::InvalidDay {
+                year: 557,
+                month: 7,
+                day: 0
+            })
+        );
+    }
+
+    #[test]
+    fn test_to_invalid_gregorian_date() {
+        assert_eq!(
+            to(2025, 2, 30).err(),
+            Some(NanakshahiError::InvalidGregorianDate {
+                year: 2025,
+                month: 2,
+                day: 30
+            })
+        );
+    }
+
+    #[test]
+    fn test_from_year_out_of_range() {
+        assert_eq!(
+            from(i32::MAX, 1, 1).err(),
+            Some(NanakshahiError::YearOutOfRange(i32::MAX))
+        );
+        assert_eq!(
+            from(300000, 1, 1).err(),
+            Some(NanakshahiError::YearOutOfRange(300000))
+        );
+    }
+
+    #[test]
+    fn test_to_across_epoch() {
+        let date: NanakshahiDate = to(1468, 3, 14).unwrap();
+        assert_eq!(date.year, 0);
+        assert_eq!(date.month, NanakshahiMonth::Chet);
+        assert_eq!(date.day, 1);
+
+        // 1468 is a Gregorian leap year, so year -1 ends on 31 Phaggan.
+        let date: NanakshahiDate = to(1468, 3, 13).unwrap();
+        assert_eq!(date.year, -1);
+        assert_eq!(date.month, NanakshahiMonth::Phaggan);
+        assert_eq!(date.day, 31);
+    }
+
+    #[test]
+    fn test_from_across_epoch() {
+        let date = from(0, 1, 1).unwrap();
+        assert_eq!((date.year(), date.month(), date.day()), (1468, 3, 14));
+
+        let date = from(-1, 12, 31).unwrap();
+        assert_eq!((date.year(), date.month(), date.day()), (1468, 3, 13));
+
+        let date = from(-1469, 1, 1).unwrap();
+        assert_eq!((date.year(), date.month(), date.day()), (-1, 3, 14));
+    }
+
+    #[test]
+    fn test_round_trip_before_epoch() {
+        let mut date = NaiveDate::from_ymd_opt(1460, 1, 1).unwrap();
+        while date < NaiveDate::from_ymd_opt(1476, 1, 1).unwrap() {
+            let nanakshahi = to(date.year(), date.month() as u8, date.day() as u8).unwrap();
+            assert_eq!(
+                from(nanakshahi.year, nanakshahi.month.number(), nanakshahi.day),
+                Ok(date)
+            );
+            date += Duration::days(1);
+        }
     }
 }
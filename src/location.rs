@@ -0,0 +1,44 @@
+use chrono::FixedOffset;
+
+/// India Standard Time, UTC+05:30.
+pub(crate) const IST: FixedOffset =
+    FixedOffset::east_opt(5 * 3600 + 30 * 60).expect("Invalid offset");
+
+/// A place on Earth, used to compute sunrise and other local events.
+///
+/// Latitude is positive north and longitude positive east, both in degrees.
+/// Elevation is in metres above sea level. The offset is the local time zone
+/// in which calendar dates are reckoned.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Location {
+    pub latitude: f64,
+    pub longitude: f64,
+    pub elevation: f64,
+    pub offset: FixedOffset,
+}
+
+impl Location {
+    /// Sri Harmandir Sahib, Amritsar, on India Standard Time.
+    pub const AMRITSAR: Location = Location {
+        latitude: 31.62,
+        longitude: 74.877,
+        elevation: 230.0,
+        offset: IST,
+    };
+
+    /// A location at the given coordinates.
+    pub fn new(latitude: f64, longitude: f64, elevation: f64, offset: FixedOffset) -> Self {
+        Location {
+            latitude,
+            longitude,
+            elevation,
+            offset,
+        }
+    }
+}
+
+impl Default for Location {
+    fn default() -> Self {
+        Location::AMRITSAR
+    }
+}
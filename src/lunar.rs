@@ -0,0 +1,856 @@
+//! The lunisolar Bikrami calendar, by which Guru Nanak's Prakash Purab,
+//! Bandi Chhor Divas and Hola Mohalla are still observed.
+//!
+//! A lunar day, or tithi, lasts while the moon gains 12° on the sun, so that
+//! thirty tithis make up a lunation. The bright fortnight (sudi) runs from new
+//! moon to full moon (Puranmashi) and the dark fortnight (vadi) from full moon
+//! to new moon (Masya).
+//!
+//! A lunation is named after the rashi the sun is in at the new moon that
+//! begins it. One in which the sun enters no new rashi is an adhik
+//! (intercalary) month and shares its name with the month that follows. As
+//! in Punjab, months are reckoned from full moon to full moon, so each vadi
+//! fortnight belongs to the month of the sudi fortnight after it. The Bikrami
+//! Samvat begins on Chet Sudi 1.
+//!
+//! The sun and moon are placed by astronomical formulae, with the rashis
+//! measured under the Lahiri ayanamsa. Tithi boundaries come out within a few
+//! minutes, so a day whose tithi changes close to sunrise may differ from a
+//! printed jantri.
+
+use std::fmt;
+
+use chrono::{
+    DateTime, Datelike, Duration, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Utc,
+};
+
+use crate::astro::{self, SYNODIC_MONTH};
+use crate::location::IST;
+use crate::solar;
+use crate::{
+    Ayanamsa, Location, NanakshahiDate, NanakshahiError, NanakshahiMonth, Rashi, Script,
+    SAMVAT_OFFSET,
+};
+
+/// Degrees of lunar phase in one tithi.
+const TITHI_DEGREES: f64 = 12.0;
+
+/// A fortnight of the lunar month.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Paksha {
+    /// The dark fortnight, from full moon to new moon.
+    Vadi,
+    /// The bright fortnight, from new moon to full moon.
+    Sudi,
+}
+
+impl Paksha {
+    /// The romanized Punjabi name of the fortnight.
+    pub fn name(self) -> &'static str {
+        self.name_in(Script::Latin)
+    }
+
+    /// The name of the fortnight in the given script.
+    pub fn name_in(self, script: Script) -> &'static str {
+        match (self, script) {
+            (Paksha::Vadi, Script::Latin) => "Vadi",
+            (Paksha::Vadi, Script::Gurmukhi) => "ਵਦੀ",
+            (Paksha::Vadi, Script::Shahmukhi) => "ودی",
+            (Paksha::Vadi, Script::Devanagari) => "वदी",
+            (Paksha::Sudi, Script::Latin) => "Sudi",
+            (Paksha::Sudi, Script::Gurmukhi) => "ਸੁਦੀ",
+            (Paksha::Sudi, Script::Shahmukhi) => "سدی",
+            (Paksha::Sudi, Script::Devanagari) => "सुदी",
+        }
+    }
+}
+
+impl fmt::Display for Paksha {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// A lunar day, numbered from 1 to 15 within its fortnight.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Tithi {
+    pub paksha: Paksha,
+    pub number: u8,
+}
+
+impl Tithi {
+    /// The full moon, Sudi 15.
+    pub const PURANMASHI: Tithi = Tithi {
+        paksha: Paksha::Sudi,
+        number: 15,
+    };
+
+    /// The new moon, Vadi 15.
+    pub const MASYA: Tithi = Tithi {
+        paksha: Paksha::Vadi,
+        number: 15,
+    };
+
+    /// Create a tithi, checking that its number is between 1 and 15.
+    ///
+    /// # Errors
+    /// Returns [`NanakshahiError::InvalidTithi`] for any other number.
+    pub fn new(paksha: Paksha, number: u8) -> Result<Self, NanakshahiError> {
+        if !(1..=15).contains(&number) {
+            return Err(NanakshahiError::InvalidTithi(number));
+        }
+        Ok(Tithi { paksha, number })
+    }
+
+    /// The tithi current at an instant.
+    ///
+    /// # Examples
+    /// ```
+    /// use chrono::{TimeZone, Utc};
+    /// use nanakshahi::lunar::Tithi;
+    ///
+    /// let instant = Utc.with_ymd_and_hms(2024, 11, 15, 12, 0, 0).unwrap();
+    /// assert_eq!(Tithi::at(instant), Tithi::PURANMASHI);
+    /// ```
+    pub fn at(instant: DateTime<Utc>) -> Self {
+        Tithi::from_index(phase_index(astro::lunar_phase(astro::julian_day(instant))))
+    }
+
+    /// The name of the tithi in the given script, such as "Sudi 5",
+    /// "Puranmashi" or "Masya".
+    pub fn name_in(self, script: Script) -> String {
+        let names: [&str; 2] = match script {
+            Script::Latin => ["Puranmashi", "Masya"],
+            Script::Gurmukhi => ["ਪੂਰਨਮਾਸ਼ੀ", "ਮੱਸਿਆ"],
+            Script::Shahmukhi => ["پورنماشی", "مسیا"],
+            Script::Devanagari => ["पूरनमाशी", "मस्सिया"],
+        };
+        match self {
+            Tithi::PURANMASHI => names[0].to_string(),
+            Tithi::MASYA => names[1].to_string(),
+            _ => format!(
+                "{} {}",
+                self.paksha.name_in(script),
+                script.numeral(self.number as u64)
+            ),
+        }
+    }
+
+    /// The tithi's place in the lunation, from 0 (Sudi 1) to 29 (Masya).
+    fn index(self) -> u8 {
+        match self.paksha {
+            Paksha::Sudi => self.number - 1,
+            Paksha::Vadi => self.number + 14,
+        }
+    }
+
+    fn from_index(index: u8) -> Self {
+        if index < 15 {
+            Tithi {
+                paksha: Paksha::Sudi,
+                number: index + 1,
+            }
+        } else {
+            Tithi {
+                paksha: Paksha::Vadi,
+                number: index - 14,
+            }
+        }
+    }
+}
+
+impl fmt::Display for Tithi {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.name_in(Script::Latin))
+    }
+}
+
+/// The moment at which a civil day takes its tithi.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Reckoning {
+    /// The tithi current at sunrise, as for most observances.
+    #[default]
+    Sunrise,
+    /// The tithi current at sunset, as for Diwali and Bandi Chhor Divas.
+    Sunset,
+}
+
+/// A date in the lunisolar Bikrami calendar.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct LunarDate {
+    /// The Bikrami Samvat year.
+    pub samvat: i32,
+    pub month: NanakshahiMonth,
+    /// Whether the month is an adhik (intercalary) month.
+    pub adhik: bool,
+    pub tithi: Tithi,
+}
+
+impl LunarDate {
+    /// The lunar date at an instant, or `None` if it is outside the range of
+    /// chrono.
+    pub fn at(instant: DateTime<Utc>) -> Option<Self> {
+        let julian_day: f64 = astro::julian_day(instant);
+        let lunation: Lunation = Lunation::containing(julian_day)?;
+        let mut index: u8 = phase_index(astro::lunar_phase(julian_day));
+        // Just after the new moon the phase may still read as the last tithi.
+        if index == 29 && julian_day - lunation.start < 1.0 {
+            index = 0;
+        }
+        Some(lunation.date_of(Tithi::from_index(index)))
+    }
+
+    /// The lunar date of a civil day, which takes the tithi current at
+    /// sunrise at the location.
+    ///
+    /// # Examples
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use nanakshahi::lunar::{LunarDate, Tithi};
+    /// use nanakshahi::{Location, NanakshahiMonth};
+    ///
+    /// let date = NaiveDate::from_ymd_opt(2024, 11, 15).unwrap();
+    /// let lunar = LunarDate::from_gregorian(date, &Location::AMRITSAR).unwrap();
+    /// assert_eq!(lunar.samvat, 2081);
+    /// assert_eq!(lunar.month, NanakshahiMonth::Kattak);
+    /// assert_eq!(lunar.tithi, Tithi::PURANMASHI);
+    /// ```
+    pub fn from_gregorian(date: NaiveDate, location: &Location) -> Option<Self> {
+        let sunrise: f64 = day_boundary(date, location, Reckoning::Sunrise);
+        LunarDate::at(astro::from_julian_day(sunrise)?)
+    }
+
+    /// The civil day at the location on which this lunar date falls.
+    ///
+    /// That is the first day whose sunrise, or sunset, falls within the
+    /// tithi. A tithi that spans neither is observed on the day it begins.
+    ///
+    /// # Errors
+    /// Returns [`NanakshahiError::InvalidLunarMonth`] if the Samvat year has
+    /// no such month, as for an adhik month in most years, or
+    /// [`NanakshahiError::InvalidTithi`] if the tithi is not valid.
+    ///
+    /// # Examples
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use nanakshahi::lunar::{LunarDate, Reckoning, Tithi};
+    /// use nanakshahi::{Location, NanakshahiMonth};
+    ///
+    /// let prakash_purab = LunarDate {
+    ///     samvat: 2082,
+    ///     month: NanakshahiMonth::Kattak,
+    ///     adhik: false,
+    ///     tithi: Tithi::PURANMASHI,
+    /// };
+    /// assert_eq!(
+    ///     prakash_purab.to_gregorian(&Location::AMRITSAR, Reckoning::Sunrise)?,
+    ///     NaiveDate::from_ymd_opt(2025, 11, 5).unwrap()
+    /// );
+    /// # Ok::<(), nanakshahi::NanakshahiError>(())
+    /// ```
+    pub fn to_gregorian(
+        &self,
+        location: &Location,
+        reckoning: Reckoning,
+    ) -> Result<NaiveDate, NanakshahiError> {
+        let tithi: Tithi = Tithi::new(self.tithi.paksha, self.tithi.number)?;
+        let invalid = NanakshahiError::InvalidLunarMonth {
+            samvat: self.samvat,
+            month: self.month.number(),
+            adhik: self.adhik,
+        };
+
+        let lunation: Lunation = self.lunation().ok_or(invalid)?;
+        let index: u8 = tithi.index();
+        let start: f64 = lunation.tithi_start(index);
+        let end: f64 = lunation.tithi_start(index + 1);
+        civil_day(start, end, location, reckoning).ok_or(invalid)
+    }
+
+    /// The date written out in the given script, such as
+    /// "Kattak Puranmashi, 2081" or "Adhik Sawan Sudi 7, 2080".
+    pub fn to_string_in(&self, script: Script) -> String {
+        let adhik: &str = match script {
+            Script::Latin => "Adhik ",
+            Script::Gurmukhi => "ਅਧਿਕ ",
+            Script::Shahmukhi => "ادھک ",
+            Script::Devanagari => "अधिक ",
+        };
+        format!(
+            "{}{} {}, {}",
+            if self.adhik { adhik } else { "" },
+            self.month.name_in(script),
+            self.tithi.name_in(script),
+            numeral(self.samvat, script)
+        )
+    }
+
+    /// The lunation, from new moon to new moon, that contains this date.
+    fn lunation(&self) -> Option<Lunation> {
+        let (month, adhik) = match self.tithi.paksha {
+            Paksha::Vadi if !self.adhik => (previous_month(self.month), false),
+            _ => (self.month, self.adhik),
+        };
+
+        // Chet Sudi 1 falls between mid-March and mid-April.
+        let first_chet: f64 = astro::julian_day_at(
+            NaiveDate::from_ymd_opt(self.samvat.checked_sub(SAMVAT_OFFSET)?, 3, 29)?,
+            NaiveTime::MIN,
+        );
+        let estimate: f64 = first_chet + (month.number() - 1) as f64 * SYNODIC_MONTH;
+        let mut lunation: Lunation = Lunation::containing(estimate - SYNODIC_MONTH)?;
+        for _ in 0..4 {
+            if (lunation.samvat, lunation.month, lunation.adhik) == (self.samvat, month, adhik) {
+                return Some(lunation);
+            }
+            lunation = Lunation::starting(lunation.end)?;
+        }
+        None
+    }
+}
+
+impl fmt::Display for LunarDate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.to_string_in(Script::Latin))
+    }
+}
+
+/// A new or full moon.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MoonPhase {
+    /// The new moon, also called Amavas.
+    Masya,
+    /// The full moon.
+    Puranmashi,
+}
+
+impl MoonPhase {
+    /// The name of the phase in the given script.
+    pub fn name_in(self, script: Script) -> String {
+        let tithi: Tithi = match self {
+            MoonPhase::Masya => Tithi::MASYA,
+            MoonPhase::Puranmashi => Tithi::PURANMASHI,
+        };
+        tithi.name_in(script)
+    }
+
+    /// The lunar phase at which it occurs, in degrees.
+    fn degrees(self) -> f64 {
+        match self {
+            MoonPhase::Masya => 0.0,
+            MoonPhase::Puranmashi => 180.0,
+        }
+    }
+}
+
+impl fmt::Display for MoonPhase {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.name_in(Script::Latin))
+    }
+}
+
+/// The instant of a new or full moon.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct MoonEvent {
+    pub phase: MoonPhase,
+    pub instant: DateTime<Utc>,
+    /// The Nanakshahi date of the instant in the chosen time zone.
+    ///
+    /// A tithi ends at the instant of the new or full moon, so the day
+    /// observed as Masya or Puranmashi, which takes its tithi at sunrise, is
+    /// often the day before. See [`LunarDate::to_gregorian`] for that day.
+    pub date: NanakshahiDate,
+}
+
+impl MoonEvent {
+    /// The lunar date whose tithi ends at the event, the Masya or
+    /// Puranmashi of its lunar month, or `None` if it is outside the range of
+    /// chrono.
+    pub fn lunar_date(&self) -> Option<LunarDate> {
+        // The event ends the tithi, so name the month from just before it.
+        let lunar: LunarDate = LunarDate::at(self.instant - Duration::hours(1))?;
+        let tithi: Tithi = match self.phase {
+            MoonPhase::Masya => Tithi::MASYA,
+            MoonPhase::Puranmashi => Tithi::PURANMASHI,
+        };
+        Some(LunarDate { tithi, ..lunar })
+    }
+
+    /// The day observed as Masya or Puranmashi at the location, which takes
+    /// its tithi at sunrise.
+    ///
+    /// # Errors
+    /// Returns [`NanakshahiError::YearOutOfRange`] if the event is outside
+    /// the range of chrono.
+    ///
+    /// # Examples
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use nanakshahi::lunar::{self, MoonPhase};
+    /// use nanakshahi::Location;
+    ///
+    /// let full_moon = lunar::moon_phases(557)?
+    ///     .find(|event| event.phase == MoonPhase::Puranmashi && event.date.month.number() == 8)
+    ///     .unwrap();
+    /// assert_eq!(
+    ///     full_moon.observed_date(&Location::AMRITSAR)?,
+    ///     NaiveDate::from_ymd_opt(2025, 11, 5).unwrap()
+    /// );
+    /// # Ok::<(), nanakshahi::NanakshahiError>(())
+    /// ```
+    pub fn observed_date(&self, location: &Location) -> Result<NaiveDate, NanakshahiError> {
+        let lunar: LunarDate = self
+            .lunar_date()
+            .ok_or(NanakshahiError::YearOutOfRange(self.date.year))?;
+        lunar.to_gregorian(location, Reckoning::Sunrise)
+    }
+}
+
+/// An iterator over the new and full moons of a Nanakshahi year, created by
+/// [`moon_phases`] and [`moon_phases_in`].
+#[derive(Debug, Clone)]
+pub struct MoonPhases<Tz: TimeZone = FixedOffset> {
+    phase: MoonPhase,
+    next: f64,
+    end: f64,
+    tz: Tz,
+}
+
+impl<Tz: TimeZone> Iterator for MoonPhases<Tz> {
+    type Item = MoonEvent;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.next >= self.end {
+            return None;
+        }
+
+        let instant: DateTime<Utc> = astro::from_julian_day(self.next)?;
+        let local: NaiveDate = instant.with_timezone(&self.tz).date_naive();
+        let date: NanakshahiDate =
+            crate::to(local.year(), local.month() as u8, local.day() as u8).ok()?;
+        let event = MoonEvent {
+            phase: self.phase,
+            instant,
+            date,
+        };
+
+        self.phase = match self.phase {
+            MoonPhase::Masya => MoonPhase::Puranmashi,
+            MoonPhase::Puranmashi => MoonPhase::Masya,
+        };
+        self.next = astro::phase_time(self.phase.degrees(), self.next + SYNODIC_MONTH / 2.0);
+        Some(event)
+    }
+}
+
+/// The new and full moons of a Nanakshahi year, dated in India Standard
+/// Time.
+///
+/// # Errors
+/// Returns [`NanakshahiError::YearOutOfRange`] if the year cannot be
+/// converted.
+///
+/// # Examples
+/// ```
+/// use nanakshahi::lunar::{self, MoonPhase};
+/// use nanakshahi::{NanakshahiDate, NanakshahiMonth};
+///
+/// let full_moons: Vec<NanakshahiDate> = lunar::moon_phases(556)?
+///     .filter(|event| event.phase == MoonPhase::Puranmashi)
+///     .map(|event| event.date)
+///     .collect();
+/// assert_eq!(full_moons.len(), 12);
+/// assert_eq!(full_moons[8], NanakshahiDate::new(556, NanakshahiMonth::Maghar, 3)?);
+/// # Ok::<(), nanakshahi::NanakshahiError>(())
+/// ```
+pub fn moon_phases(year: i32) -> Result<MoonPhases, NanakshahiError> {
+    moon_phases_in(year, &IST)
+}
+
+/// The new and full moons of a Nanakshahi year, dated in the given time
+/// zone, with daylight saving time where it applies.
+///
+/// # Errors
+/// Returns [`NanakshahiError::YearOutOfRange`] if the year cannot be
+/// converted.
+///
+/// # Examples
+/// ```
+/// use chrono_tz::Europe::London;
+/// use nanakshahi::lunar::{self, MoonPhase};
+/// use nanakshahi::{NanakshahiDate, NanakshahiMonth};
+///
+/// // The full moon at 23:49 UTC on 23 April 2024 was after midnight in
+/// // British Summer Time.
+/// let full_moon = lunar::moon_phases_in(556, &London)?
+///     .filter(|event| event.phase == MoonPhase::Puranmashi)
+///     .nth(1)
+///     .unwrap();
+/// assert_eq!(full_moon.date, NanakshahiDate::new(556, NanakshahiMonth::Vaisakh, 11)?);
+/// # Ok::<(), nanakshahi::NanakshahiError>(())
+/// ```
+pub fn moon_phases_in<Tz: TimeZone>(year: i32, tz: &Tz) -> Result<MoonPhases<Tz>, NanakshahiError> {
+    let out_of_range = NanakshahiError::YearOutOfRange(year);
+    let start: f64 = local_midnight(crate::from(year, 1, 1)?, tz).ok_or(out_of_range)?;
+    let end: f64 = local_midnight(
+        crate::from(year.checked_add(1).ok_or(out_of_range)?, 1, 1)?,
+        tz,
+    )
+    .ok_or(out_of_range)?;
+
+    let new_moon: f64 = astro::new_moon_before(start);
+    let full_moon: f64 = astro::phase_time(180.0, new_moon + SYNODIC_MONTH / 2.0);
+    let (phase, next) = if new_moon >= start {
+        (MoonPhase::Masya, new_moon)
+    } else if full_moon >= start {
+        (MoonPhase::Puranmashi, full_moon)
+    } else {
+        (
+            MoonPhase::Masya,
+            astro::phase_time(0.0, new_moon + SYNODIC_MONTH),
+        )
+    };
+
+    Ok(MoonPhases {
+        phase,
+        next,
+        end,
+        tz: tz.clone(),
+    })
+}
+
+/// The start of a day in a time zone, as a Julian Day. Where a change of
+/// clocks skips midnight, the day starts an hour later.
+fn local_midnight<Tz: TimeZone>(date: NaiveDate, tz: &Tz) -> Option<f64> {
+    let midnight: NaiveDateTime = date.and_time(NaiveTime::MIN);
+    let start: DateTime<Tz> = tz.from_local_datetime(&midnight).earliest().or_else(|| {
+        tz.from_local_datetime(&(midnight + Duration::hours(1)))
+            .earliest()
+    })?;
+    Some(astro::julian_day(start.with_timezone(&Utc)))
+}
+
+/// A lunation from one new moon to the next, named as an amanta month.
+#[derive(Debug, Clone, Copy)]
+struct Lunation {
+    start: f64,
+    end: f64,
+    samvat: i32,
+    month: NanakshahiMonth,
+    adhik: bool,
+}
+
+impl Lunation {
+    fn containing(julian_day: f64) -> Option<Self> {
+        Lunation::starting(astro::new_moon_before(julian_day))
+    }
+
+    fn starting(start: f64) -> Option<Self> {
+        let end: f64 = astro::phase_time(0.0, start + SYNODIC_MONTH);
+        let sign: Rashi = rashi(start);
+        let month: NanakshahiMonth = NanakshahiMonth::ALL[(sign as usize + 1) % 12];
+
+        let date: NaiveDate = astro::from_julian_day(start)?.date_naive();
+        let before_new_year: bool = month >= NanakshahiMonth::Poh && date.month() <= 6;
+        Some(Lunation {
+            start,
+            end,
+            samvat: date.year() + SAMVAT_OFFSET - before_new_year as i32,
+            month,
+            adhik: rashi(end) == sign,
+        })
+    }
+
+    /// The instant at which the tithi at an index begins, or at 30 the end of
+    /// the lunation.
+    fn tithi_start(&self, index: u8) -> f64 {
+        match index {
+            0 => self.start,
+            30 => self.end,
+            _ => {
+                let phase: f64 = index as f64 * TITHI_DEGREES;
+                astro::phase_time(phase, self.start + phase / 360.0 * SYNODIC_MONTH)
+            }
+        }
+    }
+
+    /// The full-moon-to-full-moon date of a tithi in this lunation.
+    fn date_of(&self, tithi: Tithi) -> LunarDate {
+        let (month, adhik) = match tithi.paksha {
+            Paksha::Vadi if !self.adhik => (next_month(self.month), false),
+            _ => (self.month, self.adhik),
+        };
+        LunarDate {
+            samvat: self.samvat,
+            month,
+            adhik,
+            tithi,
+        }
+    }
+}
+
+/// The rashi the sun is in, under the Lahiri ayanamsa.
+fn rashi(julian_day: f64) -> Rashi {
+    Rashi::containing(Ayanamsa::Lahiri.sun_longitude(julian_day))
+}
+
+fn phase_index(phase: f64) -> u8 {
+    ((phase / TITHI_DEGREES) as u8).min(29)
+}
+
+fn next_month(month: NanakshahiMonth) -> NanakshahiMonth {
+    NanakshahiMonth::ALL[month as usize % 12]
+}
+
+fn previous_month(month: NanakshahiMonth) -> NanakshahiMonth {
+    NanakshahiMonth::ALL[(month as usize + 10) % 12]
+}
+
+pub(crate) fn numeral(year: i32, script: Script) -> String {
+    let digits: String = script.numeral(year.unsigned_abs() as u64);
+    if year < 0 {
+        format!("-{digits}")
+    } else {
+        digits
+    }
+}
+
+/// The instant of sunrise or sunset on a civil day, falling back to 06:00
+/// or 18:00 local time when the sun does not rise or set.
+pub(crate) fn day_boundary(date: NaiveDate, location: &Location, reckoning: Reckoning) -> f64 {
+    let rising: bool = reckoning == Reckoning::Sunrise;
+    solar::sun_event(date, location, rising).unwrap_or_else(|| {
+        let offset: f64 = location.offset.local_minus_utc() as f64 / 86_400.0;
+        let noon: f64 = astro::julian_day_at(date, NaiveTime::MIN) + 0.5 - offset;
+        if rising {
+            noon - 0.25
+        } else {
+            noon + 0.25
+        }
+    })
+}
+
+/// The first civil day whose boundary falls between `start` and `end`.
+fn civil_day(start: f64, end: f64, location: &Location, reckoning: Reckoning) -> Option<NaiveDate> {
+    let offset = Duration::seconds(location.offset.local_minus_utc() as i64);
+    let first: NaiveDate = (astro::from_julian_day(start)? + offset).date_naive();
+    for date in [first, first.succ_opt()?] {
+        if (start..end).contains(&day_boundary(date, location, reckoning)) {
+            return Some(date);
+        }
+    }
+    Some(first)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn naive(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    fn lunar(samvat: i32, month: NanakshahiMonth, adhik: bool, tithi: Tithi) -> LunarDate {
+        LunarDate {
+            samvat,
+            month,
+            adhik,
+            tithi,
+        }
+    }
+
+    #[test]
+    fn test_tithi_at() {
+        // The new moon of 29 March 2025 at 10:58 UTC.
+        assert_eq!(
+            Tithi::at(Utc.with_ymd_and_hms(2025, 3, 29, 10, 50, 0).unwrap()),
+            Tithi::MASYA
+        );
+        assert_eq!(
+            Tithi::at(Utc.with_ymd_and_hms(2025, 3, 29, 11, 5, 0).unwrap()),
+            Tithi::new(Paksha::Sudi, 1).unwrap()
+        );
+        assert_eq!(
+            Tithi::new(Paksha::Vadi, 16),
+            Err(NanakshahiError::InvalidTithi(16))
+        );
+    }
+
+    #[test]
+    fn test_from_gregorian() {
+        let location = Location::AMRITSAR;
+
+        assert_eq!(
+            LunarDate::from_gregorian(naive(2025, 3, 30), &location),
+            Some(lunar(
+                2082,
+                NanakshahiMonth::Chet,
+                false,
+                Tithi::new(Paksha::Sudi, 1).unwrap()
+            ))
+        );
+        // Holi, the day before Hola Mohalla, and the start of Chet Vadi.
+        assert_eq!(
+            LunarDate::from_gregorian(naive(2025, 3, 14), &location),
+            Some(lunar(
+                2081,
+                NanakshahiMonth::Phaggan,
+                false,
+                Tithi::PURANMASHI
+            ))
+        );
+        assert_eq!(
+            LunarDate::from_gregorian(naive(2025, 3, 15), &location),
+            Some(lunar(
+                2081,
+                NanakshahiMonth::Chet,
+                false,
+                Tithi::new(Paksha::Vadi, 1).unwrap()
+            ))
+        );
+    }
+
+    #[test]
+    fn test_adhik_month() {
+        let location = Location::AMRITSAR;
+
+        // Samvat 2080 had an adhik Sawan from 18 July to 16 August 2023.
+        let date = LunarDate::from_gregorian(naive(2023, 7, 25), &location).unwrap();
+        assert_eq!((date.month, date.adhik), (NanakshahiMonth::Sawan, true));
+        assert_eq!(date.tithi.paksha, Paksha::Sudi);
+        assert_eq!(
+            date.to_string(),
+            format!("Adhik Sawan {}, 2080", date.tithi)
+        );
+
+        let date = LunarDate::from_gregorian(naive(2023, 8, 25), &location).unwrap();
+        assert_eq!((date.month, date.adhik), (NanakshahiMonth::Sawan, false));
+
+        assert_eq!(
+            lunar(2081, NanakshahiMonth::Sawan, true, Tithi::PURANMASHI)
+                .to_gregorian(&location, Reckoning::Sunrise),
+            Err(NanakshahiError::InvalidLunarMonth {
+                samvat: 2081,
+                month: 5,
+                adhik: true
+            })
+        );
+    }
+
+    #[test]
+    fn test_to_gregorian() {
+        let location = Location::AMRITSAR;
+
+        let prakash_purab = lunar(2081, NanakshahiMonth::Kattak, false, Tithi::PURANMASHI);
+        assert_eq!(
+            prakash_purab.to_gregorian(&location, Reckoning::Sunrise),
+            Ok(naive(2024, 11, 15))
+        );
+
+        // Diwali is reckoned at sunset, as the new moon begins in the afternoon.
+        let bandi_chhor = lunar(2082, NanakshahiMonth::Kattak, false, Tithi::MASYA);
+        assert_eq!(
+            bandi_chhor.to_gregorian(&location, Reckoning::Sunset),
+            Ok(naive(2025, 10, 20))
+        );
+        assert_eq!(
+            bandi_chhor.to_gregorian(&location, Reckoning::Sunrise),
+            Ok(naive(2025, 10, 21))
+        );
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let location = Location::AMRITSAR;
+        let mut date = naive(2024, 1, 1);
+        while date < naive(2026, 1, 1) {
+            let lunar = LunarDate::from_gregorian(date, &location).unwrap();
+            let back = lunar.to_gregorian(&location, Reckoning::Sunrise).unwrap();
+            // A tithi current at two sunrises is observed on the first.
+            assert!(
+                back == date || back.succ_opt() == Some(date),
+                "{date} {lunar}"
+            );
+            date = date.succ_opt().unwrap();
+        }
+    }
+
+    #[test]
+    fn test_moon_phases() {
+        let events: Vec<MoonEvent> = moon_phases(557).unwrap().collect();
+
+        assert_eq!(events.len(), 25);
+        assert!(events.windows(2).all(|pair| pair[0].phase != pair[1].phase));
+        assert!(events.iter().all(|event| event.date.year == 557));
+
+        // The year opens with the full moon of 14 March 2025, followed by the
+        // new moon of 29 March at 10:58 UTC, 16:28 IST.
+        assert_eq!(events[0].phase, MoonPhase::Puranmashi);
+        assert_eq!(
+            events[0].date,
+            NanakshahiDate::new(557, NanakshahiMonth::Chet, 1).unwrap()
+        );
+        let new_moon = events[1];
+        assert_eq!(new_moon.phase, MoonPhase::Masya);
+        assert_eq!(
+            new_moon.date,
+            NanakshahiDate::new(557, NanakshahiMonth::Chet, 16).unwrap()
+        );
+        assert!(
+            (new_moon.instant - Utc.with_ymd_and_hms(2025, 3, 29, 10, 58, 0).unwrap())
+                .num_minutes()
+                .abs()
+                <= 3
+        );
+    }
+
+    #[test]
+    fn test_moon_phases_in_time_zone() {
+        // The full moon of 15 November 2024 at 21:28 UTC is on the 15th in
+        // Vancouver and the 16th in Amritsar.
+        let full_moon = |offset: FixedOffset| {
+            moon_phases_in(556, &offset)
+                .unwrap()
+                .find(|event| event.instant.month() == 11 && event.phase == MoonPhase::Puranmashi)
+                .unwrap()
+                .date
+        };
+
+        assert_eq!(
+            full_moon(FixedOffset::west_opt(8 * 3600).unwrap()),
+            NanakshahiDate::new(556, NanakshahiMonth::Maghar, 2).unwrap()
+        );
+        assert_eq!(
+            full_moon(IST),
+            NanakshahiDate::new(556, NanakshahiMonth::Maghar, 3).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_moon_phases_in_daylight_saving_time() {
+        use chrono_tz::Europe::London;
+
+        let full_moon = |year: i32, month: u32| {
+            moon_phases_in(year, &London)
+                .unwrap()
+                .find(|event| {
+                    event.instant.month() == month && event.phase == MoonPhase::Puranmashi
+                })
+                .unwrap()
+                .date
+        };
+
+        // 23:49 UTC on 23 April 2024 was 00:49 in British Summer Time.
+        assert_eq!(
+            full_moon(556, 4),
+            NanakshahiDate::new(556, NanakshahiMonth::Vaisakh, 11).unwrap()
+        );
+        // 23:14 UTC on 4 December 2025 was the same time in Greenwich Mean
+        // Time.
+        assert_eq!(
+            full_moon(557, 12),
+            NanakshahiDate::new(557, NanakshahiMonth::Maghar, 21).unwrap()
+        );
+    }
+}
@@ -0,0 +1,463 @@
+//! Convert dates between the Gregorian and Nanakshahi calendars from the
+//! command line.
+
+use std::env;
+use std::fmt::Write;
+use std::io::{self, IsTerminal};
+use std::process::ExitCode;
+
+use chrono::{Datelike, Local, NaiveDate};
+use nanakshahi::cal::{self, CalOptions};
+use nanakshahi::jantri::{self, JantriOptions, Layout};
+use nanakshahi::{CalendarVariant, NanakshahiMonth, Script, VariantDate};
+
+const USAGE: &str = "\
+Usage: nanakshahi <COMMAND> [OPTIONS]
+
+Commands:
+  to <YYYY-MM-DD>   Convert a Gregorian date to a Nanakshahi date
+  from <DATE>       Convert a Nanakshahi date, such as 557-01-01 or
+                    \"1 Chet 557\", to a Gregorian date
+  today             Show today's Nanakshahi date in the local time zone
+  cal [MONTH] [YEAR]
+                    Show a month, by default the current one, as a grid
+                    with Gregorian dates and observances; with a year
+                    alone or -y, show the whole year
+  jantri [YEAR]     Print a jantri of the year, by default the current
+                    one, as an HTML page for printing
+
+Options:
+  --variant <VARIANT>  original (2003), amended (2010) or bikrami
+  --script <SCRIPT>    latin, gurmukhi, shahmukhi or devanagari
+  --format <PATTERN>   Format the result with a strftime-style pattern
+  --json               Print the result as JSON
+  -y, --year           Show the whole year with cal
+  --one-page           Fit the jantri on one page
+  -h, --help           Show this help
+  -V, --version        Show the version
+";
+
+/// What the command line asks for.
+#[derive(Debug, Clone, PartialEq)]
+enum Command {
+    To(String),
+    From(String),
+    Today,
+    Cal(Vec<String>),
+    Jantri(Option<String>),
+    Help,
+    Version,
+}
+
+/// The parsed command line.
+#[derive(Debug, Clone, PartialEq)]
+struct Options {
+    command: Command,
+    variant: CalendarVariant,
+    script: Script,
+    format: Option<String>,
+    json: bool,
+    whole_year: bool,
+    one_page: bool,
+    /// Whether to highlight with ANSI escape sequences, decided by the
+    /// terminal rather than the arguments.
+    color: bool,
+}
+
+fn main() -> ExitCode {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let mut options: Options = match parse_args(&args) {
+        Ok(options) => options,
+        Err(message) => {
+            eprintln!("error: {message}\n\n{USAGE}");
+            return ExitCode::from(2);
+        }
+    };
+    options.color = io::stdout().is_terminal() && env::var_os("NO_COLOR").is_none();
+
+    match run(&options, Local::now().date_naive()) {
+        Ok(output) => {
+            println!("{output}");
+            ExitCode::SUCCESS
+        }
+        Err(message) => {
+            eprintln!("error: {message}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn parse_args(args: &[String]) -> Result<Options, String> {
+    let mut command: Option<Command> = None;
+    let mut options: Options = Options {
+        command: Command::Help,
+        variant: CalendarVariant::default(),
+        script: Script::default(),
+        format: None,
+        json: false,
+        whole_year: false,
+        one_page: false,
+        color: false,
+    };
+
+    // Options may come anywhere, so the command and its arguments are only
+    // read once every option has been taken out.
+    let mut positionals: Vec<&str> = Vec::new();
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        let mut value = |name: &str| {
+            args.next()
+                .ok_or_else(|| format!("{name} requires a value"))
+        };
+        match arg.as_str() {
+            "--variant" => options.variant = parse_variant(value(arg)?)?,
+            "--script" => options.script = parse_script(value(arg)?)?,
+            "--format" => options.format = Some(value(arg)?.clone()),
+            "--json" => options.json = true,
+            "-y" | "--year" => options.whole_year = true,
+            "--one-page" => options.one_page = true,
+            "-h" | "--help" => command = Some(Command::Help),
+            "-V" | "--version" => command = Some(Command::Version),
+            _ if arg.starts_with('-') => return Err(format!("unknown option {arg}")),
+            _ => positionals.push(arg),
+        }
+    }
+
+    if command.is_none() {
+        command = match positionals.as_slice() {
+            [] => None,
+            [name @ ("to" | "from")] => return Err(format!("{name} requires a value")),
+            ["to", date] => Some(Command::To(date.to_string())),
+            ["from", date] => Some(Command::From(date.to_string())),
+            ["today"] => Some(Command::Today),
+            ["cal", args @ ..] if args.len() <= 2 => Some(Command::Cal(
+                args.iter().map(|arg| arg.to_string()).collect(),
+            )),
+            ["jantri"] => Some(Command::Jantri(None)),
+            ["jantri", year] => Some(Command::Jantri(Some(year.to_string()))),
+            ["to" | "from" | "today" | "cal" | "jantri", .., extra] => {
+                return Err(format!("unexpected argument {extra}"));
+            }
+            [name, ..] => return Err(format!("unknown command {name}")),
+        };
+    }
+
+    options.command = command.unwrap_or(Command::Help);
+    Ok(options)
+}
+
+fn parse_variant(name: &str) -> Result<CalendarVariant, String> {
+    match name.to_ascii_lowercase().as_str() {
+        "original" | "2003" => Ok(CalendarVariant::Original2003),
+        "amended" | "2010" => Ok(CalendarVariant::Amended2010),
+        "bikrami" => Ok(CalendarVariant::Bikrami),
+        _ => Err(format!("unknown variant {name}")),
+    }
+}
+
+fn parse_script(name: &str) -> Result<Script, String> {
+    match name.to_ascii_lowercase().as_str() {
+        "latin" => Ok(Script::Latin),
+        "gurmukhi" => Ok(Script::Gurmukhi),
+        "shahmukhi" => Ok(Script::Shahmukhi),
+        "devanagari" => Ok(Script::Devanagari),
+        _ => Err(format!("unknown script {name}")),
+    }
+}
+
+fn run(options: &Options, today: NaiveDate) -> Result<String, String> {
+    let gregorian: NaiveDate = match &options.command {
+        Command::Help => return Ok(USAGE.trim_end().to_string()),
+        Command::Version => return Ok(format!("nanakshahi {}", env!("CARGO_PKG_VERSION"))),
+        Command::To(date) => NaiveDate::parse_from_str(date, "%Y-%m-%d")
+            .map_err(|_| format!("invalid Gregorian date {date}, expected YYYY-MM-DD"))?,
+        Command::Today => today,
+        Command::Cal(args) => return calendar(options, args, today),
+        Command::Jantri(year) => return printable(options, year.as_deref(), today),
+        Command::From(date) => {
+            let date: VariantDate = options
+                .variant
+                .parse(date)
+                .map_err(|error| error.to_string())?;
+            let gregorian: NaiveDate = date.to_gregorian();
+            let formatted: String = match &options.format {
+                Some(pattern) => {
+                    let mut output: String = String::new();
+                    write!(output, "{}", gregorian.format(pattern))
+                        .map_err(|_| format!("invalid format pattern {pattern}"))?;
+                    output
+                }
+                None => gregorian.to_string(),
+            };
+            return Ok(output(options, date, formatted));
+        }
+    };
+
+    let date: VariantDate = options
+        .variant
+        .to(
+            gregorian.year(),
+            gregorian.month() as u8,
+            gregorian.day() as u8,
+        )
+        .map_err(|error| error.to_string())?;
+    let formatted: String = match &options.format {
+        Some(pattern) => {
+            let mut output: String = String::new();
+            write!(output, "{}", date.format_in(pattern, options.script))
+                .map_err(|_| format!("invalid format pattern {pattern}"))?;
+            output
+        }
+        None => date.to_string_in(options.script),
+    };
+    Ok(output(options, date, formatted))
+}
+
+/// A month or year grid, of the current month and year unless given.
+fn calendar(options: &Options, args: &[String], today: NaiveDate) -> Result<String, String> {
+    let current: VariantDate = options
+        .variant
+        .to(today.year(), today.month() as u8, today.day() as u8)
+        .map_err(|error| error.to_string())?;
+    let (month, year): (Option<NanakshahiMonth>, Option<i32>) = match args {
+        [] => (None, None),
+        // A number alone is a year, as with cal.
+        [year] if year.parse::<i32>().is_ok() => (None, Some(parse_year(year)?)),
+        [month] if options.whole_year && parse_month(month).is_ok() => {
+            return Err(format!("cal -y takes a year, not the month {month}"));
+        }
+        [year] if options.whole_year => (None, Some(parse_year(year)?)),
+        [month] => (Some(parse_month(month)?), None),
+        [month, year] => (Some(parse_month(month)?), Some(parse_year(year)?)),
+        _ => unreachable!("cal takes at most two arguments"),
+    };
+
+    let cal_options: CalOptions = CalOptions {
+        variant: options.variant,
+        script: options.script,
+        today: Some(today),
+        color: options.color,
+        ..CalOptions::default()
+    };
+    let year: i32 = year.unwrap_or(current.year());
+    let grid = if options.whole_year || (month.is_none() && !args.is_empty()) {
+        cal::year_grid(year, &cal_options)
+    } else {
+        cal::month_grid(year, month.unwrap_or(current.month()), &cal_options)
+    };
+    grid.map_err(|error| error.to_string())
+}
+
+/// A jantri of a year, by default the current one, as HTML.
+fn printable(options: &Options, year: Option<&str>, today: NaiveDate) -> Result<String, String> {
+    let year: i32 = match year {
+        Some(year) => parse_year(year)?,
+        None => options
+            .variant
+            .to(today.year(), today.month() as u8, today.day() as u8)
+            .map_err(|error| error.to_string())?
+            .year(),
+    };
+    let jantri_options: JantriOptions = JantriOptions {
+        variant: options.variant,
+        layout: if options.one_page {
+            Layout::OnePage
+        } else {
+            Layout::Monthly
+        },
+        ..JantriOptions::default()
+    };
+    jantri::html(year, &jantri_options).map_err(|error| error.to_string())
+}
+
+/// A month by its name in any script or spelling, or by its number.
+fn parse_month(name: &str) -> Result<NanakshahiMonth, String> {
+    let month: Option<NanakshahiMonth> = match name.parse::<u8>() {
+        Ok(number) => NanakshahiMonth::try_from(number).ok(),
+        Err(_) => NanakshahiMonth::from_name(name),
+    };
+    month.ok_or_else(|| format!("unknown month {name}"))
+}
+
+fn parse_year(year: &str) -> Result<i32, String> {
+    year.parse().map_err(|_| format!("invalid year {year}"))
+}
+
+/// The result as plain text or as a JSON object.
+fn output(options: &Options, date: VariantDate, formatted: String) -> String {
+    if !options.json {
+        return formatted;
+    }
+
+    format!(
+        concat!(
+            "{{\"nanakshahi\":\"{}\",\"year\":{},\"month\":{},\"month_name\":\"{}\",",
+            "\"day\":{},\"weekday\":\"{}\",\"gregorian\":\"{}\",\"formatted\":\"{}\"}}"
+        ),
+        date,
+        date.year(),
+        date.month().number(),
+        json_escape(date.month().name_in(options.script)),
+        date.day(),
+        json_escape(date.weekday().name_in(options.script)),
+        date.to_gregorian(),
+        json_escape(&formatted)
+    )
+}
+
+fn json_escape(text: &str) -> String {
+    let mut escaped: String = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            c if c.is_control() => {
+                let _ = write!(escaped, "\\u{:04x}", c as u32);
+            }
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run_args(args: &[&str]) -> Result<String, String> {
+        let args: Vec<String> = args.iter().map(|arg| arg.to_string()).collect();
+        let today = NaiveDate::from_ymd_opt(2025, 4, 14).unwrap();
+        run(&parse_args(&args)?, today)
+    }
+
+    #[test]
+    fn test_to_and_from() {
+        assert_eq!(
+            run_args(&["to", "2025-03-14"]),
+            Ok("1 Chet 557".to_string())
+        );
+        assert_eq!(
+            run_args(&["to", "2024-04-13", "--variant", "amended"]),
+            Ok("1 Vaisakh 556".to_string())
+        );
+        assert_eq!(
+            run_args(&["--script", "gurmukhi", "to", "2025-03-14"]),
+            Ok("੧ ਚੇਤ ੫੫੭".to_string())
+        );
+        assert_eq!(
+            run_args(&["from", "557-01-01"]),
+            Ok("2025-03-14".to_string())
+        );
+        assert_eq!(
+            run_args(&["from", "1 Vaisakh 557", "--format", "%d %B %Y"]),
+            Ok("14 April 2025".to_string())
+        );
+        assert_eq!(
+            run_args(&["today", "--format", "%A, %-d %B %Y %E"]),
+            Ok("Somvar, 1 Vaisakh 557 NS".to_string())
+        );
+    }
+
+    #[test]
+    fn test_variants() {
+        assert_eq!(
+            run_args(&[
+                "to",
+                "2024-04-13",
+                "--variant",
+                "amended",
+                "--format",
+                "%A %j"
+            ]),
+            Ok("Shanivar 031".to_string())
+        );
+        assert_eq!(
+            run_args(&["to", "2024-04-13", "--variant", "amended", "--json"]).unwrap(),
+            concat!(
+                r#"{"nanakshahi":"556-02-01","year":556,"month":2,"month_name":"Vaisakh","#,
+                r#""day":1,"weekday":"Shanivar","gregorian":"2024-04-13","formatted":"1 Vaisakh 556"}"#
+            )
+        );
+        assert_eq!(
+            run_args(&["from", "557-05-32", "--variant", "bikrami"]),
+            Ok("2025-08-16".to_string())
+        );
+        assert_eq!(
+            run_args(&["from", "32 Sawan 557", "--variant", "bikrami", "--json"]).unwrap(),
+            concat!(
+                r#"{"nanakshahi":"557-05-32","year":557,"month":5,"month_name":"Sawan","#,
+                r#""day":32,"weekday":"Shanivar","gregorian":"2025-08-16","formatted":"2025-08-16"}"#
+            )
+        );
+        assert!(run_args(&["from", "557-05-32"]).is_err());
+    }
+
+    #[test]
+    fn test_json() {
+        assert_eq!(
+            run_args(&["today", "--json"]).unwrap(),
+            concat!(
+                r#"{"nanakshahi":"557-02-01","year":557,"month":2,"month_name":"Vaisakh","#,
+                r#""day":1,"weekday":"Somvar","gregorian":"2025-04-14","formatted":"1 Vaisakh 557"}"#
+            )
+        );
+        // Options may come before the date they apply to.
+        assert_eq!(
+            run_args(&["to", "--json", "2025-04-14"]),
+            run_args(&["today", "--json"])
+        );
+    }
+
+    #[test]
+    fn test_cal() {
+        let month = run_args(&["cal"]).unwrap();
+        assert!(month.starts_with("        Vaisakh 557\n"), "{month}");
+        assert_eq!(run_args(&["cal", "vaisakh", "557"]), Ok(month.clone()));
+
+        let year = run_args(&["cal", "557"]).unwrap();
+        assert!(year.contains("Chet"), "{year}");
+        assert_eq!(run_args(&["cal", "-y"]), Ok(year));
+
+        assert_eq!(run_args(&["cal", "Baisakh"]), Ok(month));
+        assert_eq!(
+            run_args(&["cal", "-y", "Poh"]),
+            Err("cal -y takes a year, not the month Poh".to_string())
+        );
+        assert_eq!(
+            run_args(&["cal", "April"]),
+            Err("unknown month April".to_string())
+        );
+    }
+
+    #[test]
+    fn test_jantri() {
+        let html = run_args(&["jantri", "557", "--one-page"]).unwrap();
+        assert!(html.starts_with("<!DOCTYPE html>"));
+        assert_eq!(html.matches("<svg ").count(), 1);
+        assert_eq!(run_args(&["jantri", "--one-page"]), Ok(html));
+    }
+
+    #[test]
+    fn test_errors() {
+        assert_eq!(
+            run_args(&["to", "2025-02-30"]),
+            Err("invalid Gregorian date 2025-02-30, expected YYYY-MM-DD".to_string())
+        );
+        assert!(run_args(&["from", "556-12-31"]).is_err());
+        assert!(run_args(&["today", "--format", "%Q"]).is_err());
+        assert_eq!(
+            run_args(&["today", "--variant", "lunar"]),
+            Err("unknown variant lunar".to_string())
+        );
+        assert_eq!(run_args(&["to"]), Err("to requires a value".to_string()));
+        assert_eq!(
+            run_args(&["to", "--json"]),
+            Err("to requires a value".to_string())
+        );
+        assert_eq!(
+            run_args(&["today", "tomorrow"]),
+            Err("unexpected argument tomorrow".to_string())
+        );
+        assert!(run_args(&[]).unwrap().starts_with("Usage:"));
+    }
+}
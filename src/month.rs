@@ -0,0 +1,151 @@
+use std::fmt;
+
+use crate::parse;
+use crate::{
+    is_leap_year, NanakshahiError, Script, NANAKSHAHI_DAYS_IN_MONTHS, NANAKSHAHI_MONTH_NAMES,
+    NANAKSHAHI_MONTH_NAMES_DEVANAGARI, NANAKSHAHI_MONTH_NAMES_GURMUKHI,
+    NANAKSHAHI_MONTH_NAMES_SHAHMUKHI,
+};
+
+/// A month of the Nanakshahi calendar.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum NanakshahiMonth {
+    Chet = 1,
+    Vaisakh = 2,
+    Jeth = 3,
+    Harh = 4,
+    Sawan = 5,
+    Bhadon = 6,
+    Assu = 7,
+    Kattak = 8,
+    Maghar = 9,
+    Poh = 10,
+    Magh = 11,
+    Phaggan = 12,
+}
+
+impl NanakshahiMonth {
+    /// All months in calendar order, starting with Chet.
+    pub const ALL: [NanakshahiMonth; 12] = [
+        NanakshahiMonth::Chet,
+        NanakshahiMonth::Vaisakh,
+        NanakshahiMonth::Jeth,
+        NanakshahiMonth::Harh,
+        NanakshahiMonth::Sawan,
+        NanakshahiMonth::Bhadon,
+        NanakshahiMonth::Assu,
+        NanakshahiMonth::Kattak,
+        NanakshahiMonth::Maghar,
+        NanakshahiMonth::Poh,
+        NanakshahiMonth::Magh,
+        NanakshahiMonth::Phaggan,
+    ];
+
+    /// The ordinal of the month, from 1 (Chet) to 12 (Phaggan).
+    pub fn number(self) -> u8 {
+        self as u8
+    }
+
+    /// The romanized name of the month.
+    pub fn name(self) -> &'static str {
+        NANAKSHAHI_MONTH_NAMES[self as usize - 1]
+    }
+
+    /// The name of the month in the given script.
+    ///
+    /// # Examples
+    /// ```
+    /// use nanakshahi::{NanakshahiMonth, Script};
+    ///
+    /// assert_eq!(NanakshahiMonth::Vaisakh.name_in(Script::Latin), "Vaisakh");
+    /// assert_eq!(NanakshahiMonth::Vaisakh.name_in(Script::Gurmukhi), "ਵੈਸਾਖ");
+    /// ```
+    pub fn name_in(self, script: Script) -> &'static str {
+        let names: &[&str; 12] = match script {
+            Script::Latin => &NANAKSHAHI_MONTH_NAMES,
+            Script::Gurmukhi => &NANAKSHAHI_MONTH_NAMES_GURMUKHI,
+            Script::Shahmukhi => &NANAKSHAHI_MONTH_NAMES_SHAHMUKHI,
+            Script::Devanagari => &NANAKSHAHI_MONTH_NAMES_DEVANAGARI,
+        };
+        names[self as usize - 1]
+    }
+
+    /// The month with a name in any script, or with an abbreviation or
+    /// common romanized spelling, ignoring ASCII case.
+    ///
+    /// # Examples
+    /// ```
+    /// use nanakshahi::NanakshahiMonth;
+    ///
+    /// assert_eq!(NanakshahiMonth::from_name("baisakh"), Some(NanakshahiMonth::Vaisakh));
+    /// assert_eq!(NanakshahiMonth::from_name("ਵੈਸਾਖ"), Some(NanakshahiMonth::Vaisakh));
+    /// assert_eq!(NanakshahiMonth::from_name("April"), None);
+    /// ```
+    pub fn from_name(name: &str) -> Option<NanakshahiMonth> {
+        parse::month_from_name(name)
+    }
+
+    /// Number of days in the month for the given Nanakshahi year.
+    ///
+    /// # Examples
+    /// ```
+    /// use nanakshahi::NanakshahiMonth;
+    ///
+    /// assert_eq!(NanakshahiMonth::Chet.days(557), 31);
+    /// assert_eq!(NanakshahiMonth::Phaggan.days(555), 31);
+    /// assert_eq!(NanakshahiMonth::Phaggan.days(556), 30);
+    /// ```
+    pub fn days(self, year: i32) -> u8 {
+        let days: i32 = NANAKSHAHI_DAYS_IN_MONTHS[self as usize - 1];
+        if self == NanakshahiMonth::Phaggan && is_leap_year(year) {
+            (days + 1) as u8
+        } else {
+            days as u8
+        }
+    }
+}
+
+impl TryFrom<u8> for NanakshahiMonth {
+    type Error = NanakshahiError;
+
+    fn try_from(month: u8) -> Result<Self, Self::Error> {
+        if (1..=12).contains(&month) {
+            Ok(NanakshahiMonth::ALL[month as usize - 1])
+        } else {
+            Err(NanakshahiError::InvalidMonth(month))
+        }
+    }
+}
+
+impl fmt::Display for NanakshahiMonth {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_try_from_number() {
+        assert_eq!(NanakshahiMonth::try_from(1), Ok(NanakshahiMonth::Chet));
+        assert_eq!(NanakshahiMonth::try_from(12), Ok(NanakshahiMonth::Phaggan));
+        assert_eq!(
+            NanakshahiMonth::try_from(13),
+            Err(NanakshahiError::InvalidMonth(13))
+        );
+    }
+
+    #[test]
+    fn test_number_and_name() {
+        for (index, month) in NanakshahiMonth::ALL.iter().enumerate() {
+            assert_eq!(month.number() as usize, index + 1);
+            assert_eq!(month.name(), NANAKSHAHI_MONTH_NAMES[index]);
+        }
+        assert_eq!(NanakshahiMonth::Assu.to_string(), "Assu");
+        assert_eq!(NanakshahiMonth::Phaggan.name_in(Script::Gurmukhi), "ਫੱਗਣ");
+        assert_eq!(NanakshahiMonth::Phaggan.name_in(Script::Shahmukhi), "پھگن");
+        assert_eq!(NanakshahiMonth::Phaggan.name_in(Script::Devanagari), "फग्गण");
+    }
+}
@@ -0,0 +1,616 @@
+//! Gurpurabs and other Sikh observances on fixed Nanakshahi dates.
+//!
+//! The catalog follows the original 2003 Nanakshahi calendar, which fixed
+//! the gurpurabs of the Gurus to solar dates. Observances that are still
+//! reckoned by the moon, such as Bandi Chhor Divas and Guru Nanak's Prakash
+//! Purab, are listed separately in [`LUNAR_OBSERVANCES`].
+//!
+//! Other gurpurabs carry the tithi on which they were held before 2003. The
+//! 2010 amendment returned the Prakash Purab of Guru Gobind Singh and the
+//! Shaheedi Purab of Guru Arjan Dev to those dates, and the Bikrami variant
+//! holds every observance with a known tithi on it. See
+//! [`observances_in_year_with`].
+
+use std::fmt;
+
+use chrono::{Datelike, NaiveDate};
+
+use crate::lunar::{LunarDate, Paksha, Reckoning, Tithi};
+use crate::{
+    CalendarVariant, Location, NanakshahiDate, NanakshahiError, NanakshahiMonth, Script,
+    VariantDate,
+};
+
+/// A Sikh Guru, or Guru Granth Sahib.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Guru {
+    Nanak = 1,
+    Angad = 2,
+    AmarDas = 3,
+    RamDas = 4,
+    Arjan = 5,
+    Hargobind = 6,
+    HarRai = 7,
+    HarKrishan = 8,
+    TeghBahadur = 9,
+    GobindSingh = 10,
+    GranthSahib = 11,
+}
+
+impl Guru {
+    /// The name of the Guru in the given script.
+    ///
+    /// Latin script names are in English, as in "Guru Nanak Dev Ji".
+    pub fn name_in(self, script: Script) -> &'static str {
+        let names: [&str; 2] = match self {
+            Guru::Nanak => ["Guru Nanak Dev Ji", "ਗੁਰੂ ਨਾਨਕ ਦੇਵ ਜੀ"],
+            Guru::Angad => ["Guru Angad Dev Ji", "ਗੁਰੂ ਅੰਗਦ ਦੇਵ ਜੀ"],
+            Guru::AmarDas => ["Guru Amar Das Ji", "ਗੁਰੂ ਅਮਰ ਦਾਸ ਜੀ"],
+            Guru::RamDas => ["Guru Ram Das Ji", "ਗੁਰੂ ਰਾਮ ਦਾਸ ਜੀ"],
+            Guru::Arjan => ["Guru Arjan Dev Ji", "ਗੁਰੂ ਅਰਜਨ ਦੇਵ ਜੀ"],
+            Guru::Hargobind => ["Guru Hargobind Ji", "ਗੁਰੂ ਹਰਿਗੋਬਿੰਦ ਜੀ"],
+            Guru::HarRai => ["Guru Har Rai Ji", "ਗੁਰੂ ਹਰਿ ਰਾਇ ਜੀ"],
+            Guru::HarKrishan => ["Guru Har Krishan Ji", "ਗੁਰੂ ਹਰਿ ਕ੍ਰਿਸ਼ਨ ਜੀ"],
+            Guru::TeghBahadur => ["Guru Tegh Bahadur Ji", "ਗੁਰੂ ਤੇਗ ਬਹਾਦਰ ਜੀ"],
+            Guru::GobindSingh => ["Guru Gobind Singh Ji", "ਗੁਰੂ ਗੋਬਿੰਦ ਸਿੰਘ ਜੀ"],
+            Guru::GranthSahib => ["Guru Granth Sahib Ji", "ਗੁਰੂ ਗ੍ਰੰਥ ਸਾਹਿਬ ਜੀ"],
+        };
+        match script {
+            Script::Gurmukhi => names[1],
+            _ => names[0],
+        }
+    }
+}
+
+impl fmt::Display for Guru {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name_in(Script::Latin))
+    }
+}
+
+/// What an observance commemorates.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum ObservanceKind {
+    /// The birth of a Guru.
+    Prakash,
+    /// The accession of a Guru.
+    Gurgaddi,
+    /// The passing of a Guru.
+    JotiJot,
+    /// A martyrdom.
+    Shaheedi,
+    /// Any other commemoration, such as Vaisakhi.
+    Other,
+}
+
+/// An observance held on the same Nanakshahi date every year.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Observance {
+    /// The English name.
+    pub name: &'static str,
+    /// The name in Gurmukhi script.
+    pub gurmukhi_name: &'static str,
+    pub kind: ObservanceKind,
+    /// The Guru commemorated, if any.
+    pub guru: Option<Guru>,
+    pub month: NanakshahiMonth,
+    pub day: u8,
+    /// The lunar month and tithi on which the observance was held before
+    /// 2003, if known.
+    pub lunar: Option<(NanakshahiMonth, Tithi)>,
+}
+
+impl Observance {
+    /// The name in the given script, falling back to English for scripts
+    /// other than Gurmukhi.
+    pub fn name_in(&self, script: Script) -> &'static str {
+        match script {
+            Script::Gurmukhi => self.gurmukhi_name,
+            _ => self.name,
+        }
+    }
+
+    /// The date of the observance in a Nanakshahi year.
+    pub fn date_in(&self, year: i32) -> Result<NanakshahiDate, NanakshahiError> {
+        NanakshahiDate::new(year, self.month, self.day)
+    }
+
+    /// Whether the observance is held on its lunar date under a calendar
+    /// variant.
+    pub fn is_lunar_in(&self, variant: CalendarVariant) -> bool {
+        match variant {
+            CalendarVariant::Original2003 => false,
+            CalendarVariant::Amended2010 => {
+                self.lunar.is_some()
+                    && self
+                        .guru
+                        .is_some_and(|guru| LUNAR_SINCE_2010.contains(&(self.kind, guru)))
+            }
+            CalendarVariant::Bikrami => self.lunar.is_some(),
+        }
+    }
+
+    const fn with_lunar_date(mut self, month: NanakshahiMonth, tithi: Tithi) -> Self {
+        self.lunar = Some((month, tithi));
+        self
+    }
+}
+
+/// An observance on a particular date.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Occurrence {
+    pub observance: &'static Observance,
+    /// The date under the calendar variant the year was reckoned in.
+    pub date: VariantDate,
+    pub gregorian: NaiveDate,
+}
+
+/// An observance held on the same tithi of a lunar month every year.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct LunarObservance {
+    /// The English name.
+    pub name: &'static str,
+    /// The name in Gurmukhi script.
+    pub gurmukhi_name: &'static str,
+    pub kind: ObservanceKind,
+    /// The Guru commemorated, if any.
+    pub guru: Option<Guru>,
+    pub month: NanakshahiMonth,
+    pub tithi: Tithi,
+    /// Whether the day takes its tithi at sunrise or at sunset.
+    pub reckoning: Reckoning,
+}
+
+impl LunarObservance {
+    /// The name in the given script, falling back to English for scripts
+    /// other than Gurmukhi.
+    pub fn name_in(&self, script: Script) -> &'static str {
+        match script {
+            Script::Gurmukhi => self.gurmukhi_name,
+            _ => self.name,
+        }
+    }
+
+    /// The Gregorian date of the observance in a Bikrami Samvat year, as
+    /// observed at the location.
+    ///
+    /// # Errors
+    /// See [`LunarDate::to_gregorian`].
+    pub fn date_in(&self, samvat: i32, location: &Location) -> Result<NaiveDate, NanakshahiError> {
+        let date = LunarDate {
+            samvat,
+            month: self.month,
+            adhik: false,
+            tithi: self.tithi,
+        };
+        date.to_gregorian(location, self.reckoning)
+    }
+}
+
+/// A lunar observance on a particular date.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct LunarOccurrence {
+    pub observance: &'static LunarObservance,
+    pub date: NanakshahiDate,
+    pub gregorian: NaiveDate,
+}
+
+const fn observance(
+    name: &'static str,
+    gurmukhi_name: &'static str,
+    kind: ObservanceKind,
+    guru: Option<Guru>,
+    month: NanakshahiMonth,
+    day: u8,
+) -> Observance {
+    Observance {
+        name,
+        gurmukhi_name,
+        kind,
+        guru,
+        month,
+        day,
+        lunar: None,
+    }
+}
+
+const fn sudi(number: u8) -> Tithi {
+    Tithi {
+        paksha: Paksha::Sudi,
+        number,
+    }
+}
+
+const fn vadi(number: u8) -> Tithi {
+    Tithi {
+        paksha: Paksha::Vadi,
+        number,
+    }
+}
+
+/// The gurpurabs that the 2010 amendment returned to their lunar dates.
+const LUNAR_SINCE_2010: [(ObservanceKind, Guru); 2] = [
+    (ObservanceKind::Shaheedi, Guru::Arjan),
+    (ObservanceKind::Prakash, Guru::GobindSingh),
+];
+
+/// All fixed-date observances, in calendar order.
+#[rustfmt::skip]
+pub const OBSERVANCES: [Observance; 34] = {
+    use NanakshahiMonth::*;
+    use ObservanceKind::*;
+
+    [
+        observance("Nanakshahi New Year", "ਨਾਨਕਸ਼ਾਹੀ ਨਵਾਂ ਸਾਲ", Other, None, Chet, 1),
+        observance("Joti Jot Guru Hargobind Ji", "ਜੋਤੀ ਜੋਤ ਗੁਰੂ ਹਰਿਗੋਬਿੰਦ ਜੀ", JotiJot, Some(Guru::Hargobind), Chet, 6),
+        observance("Gurgaddi Guru Har Rai Ji", "ਗੁਰਗੱਦੀ ਗੁਰੂ ਹਰਿ ਰਾਇ ਜੀ", Gurgaddi, Some(Guru::HarRai), Chet, 6),
+        observance("Vaisakhi (Khalsa Sajna Divas)", "ਵੈਸਾਖੀ (ਖ਼ਾਲਸਾ ਸਾਜਨਾ ਦਿਵਸ)", Other, None, Vaisakh, 1),
+        observance("Joti Jot Guru Angad Dev Ji", "ਜੋਤੀ ਜੋਤ ਗੁਰੂ ਅੰਗਦ ਦੇਵ ਜੀ", JotiJot, Some(Guru::Angad), Vaisakh, 3),
+        observance("Gurgaddi Guru Amar Das Ji", "ਗੁਰਗੱਦੀ ਗੁਰੂ ਅਮਰ ਦਾਸ ਜੀ", Gurgaddi, Some(Guru::AmarDas), Vaisakh, 3),
+        observance("Joti Jot Guru Har Krishan Ji", "ਜੋਤੀ ਜੋਤ ਗੁਰੂ ਹਰਿ ਕ੍ਰਿਸ਼ਨ ਜੀ", JotiJot, Some(Guru::HarKrishan), Vaisakh, 3),
+        observance("Gurgaddi Guru Tegh Bahadur Ji", "ਗੁਰਗੱਦੀ ਗੁਰੂ ਤੇਗ ਬਹਾਦਰ ਜੀ", Gurgaddi, Some(Guru::TeghBahadur), Vaisakh, 3),
+        observance("Prakash Guru Angad Dev Ji", "ਪ੍ਰਕਾਸ਼ ਗੁਰੂ ਅੰਗਦ ਦੇਵ ਜੀ", Prakash, Some(Guru::Angad), Vaisakh, 5),
+        observance("Prakash Guru Tegh Bahadur Ji", "ਪ੍ਰਕਾਸ਼ ਗੁਰੂ ਤੇਗ ਬਹਾਦਰ ਜੀ", Prakash, Some(Guru::TeghBahadur), Vaisakh, 5),
+        observance("Prakash Guru Arjan Dev Ji", "ਪ੍ਰਕਾਸ਼ ਗੁਰੂ ਅਰਜਨ ਦੇਵ ਜੀ", Prakash, Some(Guru::Arjan), Vaisakh, 19),
+        observance("Prakash Guru Amar Das Ji", "ਪ੍ਰਕਾਸ਼ ਗੁਰੂ ਅਮਰ ਦਾਸ ਜੀ", Prakash, Some(Guru::AmarDas), Jeth, 9),
+        observance("Gurgaddi Guru Hargobind Ji", "ਗੁਰਗੱਦੀ ਗੁਰੂ ਹਰਿਗੋਬਿੰਦ ਜੀ", Gurgaddi, Some(Guru::Hargobind), Jeth, 28),
+        observance("Shaheedi Guru Arjan Dev Ji", "ਸ਼ਹੀਦੀ ਗੁਰੂ ਅਰਜਨ ਦੇਵ ਜੀ", Shaheedi, Some(Guru::Arjan), Harh, 2).with_lunar_date(Jeth, sudi(4)),
+        observance("Prakash Guru Hargobind Ji", "ਪ੍ਰਕਾਸ਼ ਗੁਰੂ ਹਰਿਗੋਬਿੰਦ ਜੀ", Prakash, Some(Guru::Hargobind), Harh, 21),
+        observance("Prakash Guru Har Krishan Ji", "ਪ੍ਰਕਾਸ਼ ਗੁਰੂ ਹਰਿ ਕ੍ਰਿਸ਼ਨ ਜੀ", Prakash, Some(Guru::HarKrishan), Sawan, 8),
+        observance("Pehla Prakash Guru Granth Sahib Ji", "ਪਹਿਲਾ ਪ੍ਰਕਾਸ਼ ਗੁਰੂ ਗ੍ਰੰਥ ਸਾਹਿਬ ਜੀ", Prakash, Some(Guru::GranthSahib), Bhadon, 17).with_lunar_date(Bhadon, sudi(1)),
+        observance("Joti Jot Guru Amar Das Ji", "ਜੋਤੀ ਜੋਤ ਗੁਰੂ ਅਮਰ ਦਾਸ ਜੀ", JotiJot, Some(Guru::AmarDas), Assu, 2),
+        observance("Gurgaddi Guru Ram Das Ji", "ਗੁਰਗੱਦੀ ਗੁਰੂ ਰਾਮ ਦਾਸ ਜੀ", Gurgaddi, Some(Guru::RamDas), Assu, 2),
+        observance("Joti Jot Guru Ram Das Ji", "ਜੋਤੀ ਜੋਤ ਗੁਰੂ ਰਾਮ ਦਾਸ ਜੀ", JotiJot, Some(Guru::RamDas), Assu, 2),
+        observance("Gurgaddi Guru Arjan Dev Ji", "ਗੁਰਗੱਦੀ ਗੁਰੂ ਅਰਜਨ ਦੇਵ ਜੀ", Gurgaddi, Some(Guru::Arjan), Assu, 2),
+        observance("Joti Jot Guru Nanak Dev Ji", "ਜੋਤੀ ਜੋਤ ਗੁਰੂ ਨਾਨਕ ਦੇਵ ਜੀ", JotiJot, Some(Guru::Nanak), Assu, 8).with_lunar_date(Assu, vadi(10)),
+        observance("Gurgaddi Guru Angad Dev Ji", "ਗੁਰਗੱਦੀ ਗੁਰੂ ਅੰਗਦ ਦੇਵ ਜੀ", Gurgaddi, Some(Guru::Angad), Assu, 8),
+        observance("Joti Jot Guru Har Rai Ji", "ਜੋਤੀ ਜੋਤ ਗੁਰੂ ਹਰਿ ਰਾਇ ਜੀ", JotiJot, Some(Guru::HarRai), Assu, 22),
+        observance("Gurgaddi Guru Har Krishan Ji", "ਗੁਰਗੱਦੀ ਗੁਰੂ ਹਰਿ ਕ੍ਰਿਸ਼ਨ ਜੀ", Gurgaddi, Some(Guru::HarKrishan), Assu, 22),
+        observance("Prakash Guru Ram Das Ji", "ਪ੍ਰਕਾਸ਼ ਗੁਰੂ ਰਾਮ ਦਾਸ ਜੀ", Prakash, Some(Guru::RamDas), Assu, 25).with_lunar_date(Kattak, vadi(2)),
+        observance("Gurgaddi Guru Granth Sahib Ji", "ਗੁਰਗੱਦੀ ਗੁਰੂ ਗ੍ਰੰਥ ਸਾਹਿਬ ਜੀ", Gurgaddi, Some(Guru::GranthSahib), Kattak, 6),
+        observance("Joti Jot Guru Gobind Singh Ji", "ਜੋਤੀ ਜੋਤ ਗੁਰੂ ਗੋਬਿੰਦ ਸਿੰਘ ਜੀ", JotiJot, Some(Guru::GobindSingh), Kattak, 7).with_lunar_date(Kattak, sudi(5)),
+        observance("Shaheedi Guru Tegh Bahadur Ji", "ਸ਼ਹੀਦੀ ਗੁਰੂ ਤੇਗ ਬਹਾਦਰ ਜੀ", Shaheedi, Some(Guru::TeghBahadur), Maghar, 11).with_lunar_date(Maghar, sudi(5)),
+        observance("Gurgaddi Guru Gobind Singh Ji", "ਗੁਰਗੱਦੀ ਗੁਰੂ ਗੋਬਿੰਦ ਸਿੰਘ ਜੀ", Gurgaddi, Some(Guru::GobindSingh), Maghar, 11).with_lunar_date(Maghar, sudi(5)),
+        observance("Shaheedi Sahibzade Ajit Singh and Jujhar Singh", "ਸ਼ਹੀਦੀ ਸਾਹਿਬਜ਼ਾਦੇ ਅਜੀਤ ਸਿੰਘ ਅਤੇ ਜੁਝਾਰ ਸਿੰਘ", Shaheedi, None, Poh, 8),
+        observance("Shaheedi Sahibzade Zorawar Singh and Fateh Singh", "ਸ਼ਹੀਦੀ ਸਾਹਿਬਜ਼ਾਦੇ ਜ਼ੋਰਾਵਰ ਸਿੰਘ ਅਤੇ ਫ਼ਤਿਹ ਸਿੰਘ", Shaheedi, None, Poh, 13),
+        observance("Prakash Guru Gobind Singh Ji", "ਪ੍ਰਕਾਸ਼ ਗੁਰੂ ਗੋਬਿੰਦ ਸਿੰਘ ਜੀ", Prakash, Some(Guru::GobindSingh), Poh, 23).with_lunar_date(Poh, sudi(7)),
+        observance("Prakash Guru Har Rai Ji", "ਪ੍ਰਕਾਸ਼ ਗੁਰੂ ਹਰਿ ਰਾਇ ਜੀ", Prakash, Some(Guru::HarRai), Magh, 19),
+    ]
+};
+
+/// All observances reckoned by the moon, in the order of the lunar year.
+pub const LUNAR_OBSERVANCES: [LunarObservance; 3] = [
+    LunarObservance {
+        name: "Hola Mohalla",
+        gurmukhi_name: "ਹੋਲਾ ਮਹੱਲਾ",
+        kind: ObservanceKind::Other,
+        guru: None,
+        month: NanakshahiMonth::Chet,
+        tithi: vadi(1),
+        reckoning: Reckoning::Sunrise,
+    },
+    LunarObservance {
+        name: "Bandi Chhor Divas",
+        gurmukhi_name: "ਬੰਦੀ ਛੋੜ ਦਿਵਸ",
+        kind: ObservanceKind::Other,
+        guru: Some(Guru::Hargobind),
+        month: NanakshahiMonth::Kattak,
+        tithi: Tithi::MASYA,
+        reckoning: Reckoning::Sunset,
+    },
+    LunarObservance {
+        name: "Prakash Guru Nanak Dev Ji",
+        gurmukhi_name: "ਪ੍ਰਕਾਸ਼ ਗੁਰੂ ਨਾਨਕ ਦੇਵ ਜੀ",
+        kind: ObservanceKind::Prakash,
+        guru: Some(Guru::Nanak),
+        month: NanakshahiMonth::Kattak,
+        tithi: Tithi::PURANMASHI,
+        reckoning: Reckoning::Sunrise,
+    },
+];
+
+/// The observances held on a Nanakshahi date.
+///
+/// # Examples
+/// ```
+/// use nanakshahi::observances;
+///
+/// let date = nanakshahi::to(2025, 4, 14)?;
+/// let names: Vec<&str> = observances::observances_on(date).map(|o| o.name).collect();
+/// assert_eq!(names, ["Vaisakhi (Khalsa Sajna Divas)"]);
+/// # Ok::<(), nanakshahi::NanakshahiError>(())
+/// ```
+pub fn observances_on(date: NanakshahiDate) -> impl Iterator<Item = &'static Observance> {
+    OBSERVANCES
+        .iter()
+        .filter(move |observance| observance.month == date.month && observance.day == date.day)
+}
+
+/// Every observance in a Nanakshahi year with its Gregorian date, in
+/// calendar order.
+///
+/// # Examples
+/// ```
+/// use chrono::NaiveDate;
+/// use nanakshahi::observances;
+///
+/// let occurrences = observances::observances_in_year(557)?;
+/// let prakash = occurrences
+///     .iter()
+///     .find(|o| o.observance.name == "Prakash Guru Gobind Singh Ji")
+///     .unwrap();
+/// assert_eq!(prakash.gregorian, NaiveDate::from_ymd_opt(2026, 1, 5).unwrap());
+/// # Ok::<(), nanakshahi::NanakshahiError>(())
+/// ```
+pub fn observances_in_year(year: i32) -> Result<Vec<Occurrence>, NanakshahiError> {
+    OBSERVANCES
+        .iter()
+        .map(|observance| {
+            let date: VariantDate = VariantDate::from_original(observance.date_in(year)?)?;
+            Ok(Occurrence {
+                observance,
+                date,
+                gregorian: date.to_gregorian(),
+            })
+        })
+        .collect()
+}
+
+/// Every lunar observance falling in a Nanakshahi year, as observed at the
+/// location, in calendar order.
+///
+/// Hola Mohalla falls close to the start of the Nanakshahi year, so some
+/// years hold it twice and others not at all.
+///
+/// # Errors
+/// Returns [`NanakshahiError::YearOutOfRange`] if the year cannot be
+/// converted.
+///
+/// # Examples
+/// ```
+/// use chrono::NaiveDate;
+/// use nanakshahi::{observances, Location};
+///
+/// let occurrences = observances::lunar_observances_in_year(557, &Location::AMRITSAR)?;
+/// let names: Vec<&str> = occurrences.iter().map(|o| o.observance.name).collect();
+/// assert_eq!(
+///     names,
+///     [
+///         "Hola Mohalla",
+///         "Bandi Chhor Divas",
+///         "Prakash Guru Nanak Dev Ji",
+///         "Hola Mohalla"
+///     ]
+/// );
+/// assert_eq!(
+///     occurrences[2].gregorian,
+///     NaiveDate::from_ymd_opt(2025, 11, 5).unwrap()
+/// );
+/// # Ok::<(), nanakshahi::NanakshahiError>(())
+/// ```
+pub fn lunar_observances_in_year(
+    year: i32,
+    location: &Location,
+) -> Result<Vec<LunarOccurrence>, NanakshahiError> {
+    let mut occurrences: Vec<LunarOccurrence> = Vec::new();
+    for samvat in overlapping_samvats(year)? {
+        for observance in &LUNAR_OBSERVANCES {
+            let gregorian: NaiveDate = observance.date_in(samvat, location)?;
+            let date: NanakshahiDate = crate::to(
+                gregorian.year(),
+                gregorian.month() as u8,
+                gregorian.day() as u8,
+            )?;
+            if date.year == year {
+                occurrences.push(LunarOccurrence {
+                    observance,
+                    date,
+                    gregorian,
+                });
+            }
+        }
+    }
+    occurrences.sort_by_key(|occurrence| occurrence.gregorian);
+    Ok(occurrences)
+}
+
+/// Every observance in a Nanakshahi year of a calendar variant with its
+/// Gregorian date, in date order.
+///
+/// Observances held on their lunar date under the variant, as decided by
+/// [`Observance::is_lunar_in`], take the tithi current at sunrise at the
+/// location. The rest keep their day of the month, counted from the
+/// variant's month boundaries.
+///
+/// # Errors
+/// Returns [`NanakshahiError::YearOutOfRange`] if the year cannot be
+/// converted.
+///
+/// # Examples
+/// ```
+/// use chrono::NaiveDate;
+/// use nanakshahi::{observances, CalendarVariant, Location};
+///
+/// let prakash = |variant| -> Result<NaiveDate, nanakshahi::NanakshahiError> {
+///     let occurrences = observances::observances_in_year_with(556, variant, &Location::AMRITSAR)?;
+///     Ok(occurrences
+///         .iter()
+///         .find(|o| o.observance.name == "Prakash Guru Gobind Singh Ji")
+///         .unwrap()
+///         .gregorian)
+/// };
+/// assert_eq!(prakash(CalendarVariant::Original2003)?, NaiveDate::from_ymd_opt(2025, 1, 5).unwrap());
+/// assert_eq!(prakash(CalendarVariant::Amended2010)?, NaiveDate::from_ymd_opt(2025, 1, 6).unwrap());
+/// # Ok::<(), nanakshahi::NanakshahiError>(())
+/// ```
+pub fn observances_in_year_with(
+    year: i32,
+    variant: CalendarVariant,
+    location: &Location,
+) -> Result<Vec<Occurrence>, NanakshahiError> {
+    let mut occurrences: Vec<Occurrence> = Vec::new();
+    for observance in &OBSERVANCES {
+        let dates: Vec<NaiveDate> = match observance.lunar {
+            Some((month, tithi)) if observance.is_lunar_in(variant) => overlapping_samvats(year)?
+                .into_iter()
+                .map(|samvat| {
+                    let date = LunarDate {
+                        samvat,
+                        month,
+                        adhik: false,
+                        tithi,
+                    };
+                    date.to_gregorian(location, Reckoning::Sunrise)
+                })
+                .collect::<Result<_, _>>()?,
+            _ => vec![variant.from(year, observance.month.number(), observance.day)?],
+        };
+
+        for gregorian in dates {
+            let date: VariantDate = variant.to(
+                gregorian.year(),
+                gregorian.month() as u8,
+                gregorian.day() as u8,
+            )?;
+            if date.year() == year {
+                occurrences.push(Occurrence {
+                    observance,
+                    date,
+                    gregorian,
+                });
+            }
+        }
+    }
+    occurrences.sort_by_key(|occurrence| occurrence.gregorian);
+    Ok(occurrences)
+}
+
+/// The two Bikrami Samvat years that a Nanakshahi year overlaps: the one
+/// still current on 1 Chet, and the one that begins in the following spring.
+fn overlapping_samvats(year: i32) -> Result<[i32; 2], NanakshahiError> {
+    let current: i32 = year
+        .checked_add(crate::EPOCH_ON_OR_AFTER_MID_MARCH + crate::SAMVAT_OFFSET - 1)
+        .ok_or(NanakshahiError::YearOutOfRange(year))?;
+    let next: i32 = current
+        .checked_add(1)
+        .ok_or(NanakshahiError::YearOutOfRange(year))?;
+    Ok([current, next])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_catalog_is_valid_and_ordered() {
+        for observance in &OBSERVANCES {
+            assert!(observance.date_in(557).is_ok(), "{}", observance.name);
+        }
+        assert!(OBSERVANCES
+            .windows(2)
+            .all(|pair| (pair[0].month, pair[0].day) <= (pair[1].month, pair[1].day)));
+    }
+
+    #[test]
+    fn test_observances_on() {
+        let date = NanakshahiDate::new(557, NanakshahiMonth::Maghar, 11).unwrap();
+        let gurus: Vec<Option<Guru>> = observances_on(date).map(|o| o.guru).collect();
+
+        assert_eq!(gurus, [Some(Guru::TeghBahadur), Some(Guru::GobindSingh)]);
+        assert_eq!(
+            observances_on(NanakshahiDate::new(557, NanakshahiMonth::Chet, 2).unwrap()).count(),
+            0
+        );
+    }
+
+    #[test]
+    fn test_observances_in_year() {
+        let occurrences = observances_in_year(556).unwrap();
+
+        assert_eq!(occurrences.len(), OBSERVANCES.len());
+        let chhote_sahibzade = occurrences
+            .iter()
+            .find(|o| o.observance.month == NanakshahiMonth::Poh && o.observance.day == 13)
+            .unwrap();
+        assert_eq!(
+            chhote_sahibzade.gregorian,
+            NaiveDate::from_ymd_opt(2024, 12, 26).unwrap()
+        );
+        assert_eq!(
+            chhote_sahibzade.observance.name_in(Script::Gurmukhi),
+            "ਸ਼ਹੀਦੀ ਸਾਹਿਬਜ਼ਾਦੇ ਜ਼ੋਰਾਵਰ ਸਿੰਘ ਅਤੇ ਫ਼ਤਿਹ ਸਿੰਘ"
+        );
+    }
+
+    #[test]
+    fn test_lunar_observances_in_year() {
+        let occurrences = lunar_observances_in_year(556, &Location::AMRITSAR).unwrap();
+        let dates: Vec<(&str, NaiveDate)> = occurrences
+            .iter()
+            .map(|o| (o.observance.name, o.gregorian))
+            .collect();
+
+        let naive = |month, day| NaiveDate::from_ymd_opt(2024, month, day).unwrap();
+
+        assert_eq!(
+            dates,
+            [
+                ("Hola Mohalla", naive(3, 26)),
+                ("Bandi Chhor Divas", naive(10, 31)),
+                ("Prakash Guru Nanak Dev Ji", naive(11, 15)),
+            ]
+        );
+        assert_eq!(
+            occurrences[2].date,
+            NanakshahiDate::new(556, NanakshahiMonth::Maghar, 2).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_observances_in_year_with() {
+        let location = Location::AMRITSAR;
+        let find = |occurrences: &[Occurrence], kind, guru| {
+            occurrences
+                .iter()
+                .find(|o| o.observance.kind == kind && o.observance.guru == Some(guru))
+                .map(|o| o.gregorian)
+        };
+        let naive = |year, month, day| NaiveDate::from_ymd_opt(year, month, day);
+
+        let original = observances_in_year_with(556, CalendarVariant::Original2003, &location);
+        assert_eq!(original.as_ref().map(Vec::len), Ok(OBSERVANCES.len()));
+
+        let amended =
+            observances_in_year_with(556, CalendarVariant::Amended2010, &location).unwrap();
+        assert_eq!(amended.len(), OBSERVANCES.len());
+        assert_eq!(
+            find(&amended, ObservanceKind::Shaheedi, Guru::Arjan),
+            naive(2024, 6, 10)
+        );
+        // Fixed observances keep their day, counted from the sidereal Maghar.
+        assert_eq!(
+            find(&amended, ObservanceKind::Shaheedi, Guru::TeghBahadur),
+            naive(2024, 11, 26)
+        );
+        assert_ne!(
+            find(&amended, ObservanceKind::Shaheedi, Guru::TeghBahadur),
+            find(
+                &original.unwrap(),
+                ObservanceKind::Shaheedi,
+                Guru::TeghBahadur
+            )
+        );
+
+        let bikrami = observances_in_year_with(556, CalendarVariant::Bikrami, &location).unwrap();
+        assert_eq!(
+            find(&bikrami, ObservanceKind::Shaheedi, Guru::TeghBahadur),
+            naive(2024, 12, 6)
+        );
+        // Vaisakhi stays on 1 Vaisakh, which began on 13 April in 2024.
+        let vaisakhi = bikrami
+            .iter()
+            .find(|o| o.observance.name.starts_with("Vaisakhi"))
+            .unwrap();
+        assert_eq!(Some(vaisakhi.gregorian), naive(2024, 4, 13));
+    }
+}
@@ -0,0 +1,612 @@
+use chrono::{Datelike, NaiveDate};
+
+use crate::error::{ParseError, ParseErrorKind};
+use crate::format::{era, short_month_name, short_weekday_name, Item, Pad, StrftimeItems};
+use crate::{
+    CalendarVariant, NanakshahiDate, NanakshahiMonth, NanakshahiWeekday, Script, VariantDate,
+};
+
+/// Patterns tried in order when parsing with [`std::str::FromStr`].
+pub(crate) const FROM_STR_PATTERNS: [&str; 7] = [
+    "%Y-%m-%d",
+    "%d %B %Y",
+    "%d %B %N %E",
+    "%d %B, %Y",
+    "%B %d, %Y",
+    "%B %d, %N %E",
+    "%B %d %Y",
+];
+
+/// Spellings of month names found in print, in addition to the names and
+/// abbreviations that [`NanakshahiMonth`] itself produces.
+const NANAKSHAHI_MONTH_SPELLINGS: &[(&str, NanakshahiMonth)] = &[
+    ("Chait", NanakshahiMonth::Chet),
+    ("Chetar", NanakshahiMonth::Chet),
+    ("Visakh", NanakshahiMonth::Vaisakh),
+    ("Baisakh", NanakshahiMonth::Vaisakh),
+    ("Vasakh", NanakshahiMonth::Vaisakh),
+    ("Jaith", NanakshahiMonth::Jeth),
+    ("Haar", NanakshahiMonth::Harh),
+    ("Haarh", NanakshahiMonth::Harh),
+    ("Harr", NanakshahiMonth::Harh),
+    ("Asarh", NanakshahiMonth::Harh),
+    ("Saun", NanakshahiMonth::Sawan),
+    ("Savan", NanakshahiMonth::Sawan),
+    ("Bhado", NanakshahiMonth::Bhadon),
+    ("Bhadron", NanakshahiMonth::Bhadon),
+    ("Asu", NanakshahiMonth::Assu),
+    ("Asoo", NanakshahiMonth::Assu),
+    ("Katak", NanakshahiMonth::Kattak),
+    ("Katik", NanakshahiMonth::Kattak),
+    ("Kartik", NanakshahiMonth::Kattak),
+    ("Magghar", NanakshahiMonth::Maghar),
+    ("Magar", NanakshahiMonth::Maghar),
+    ("Phagan", NanakshahiMonth::Phaggan),
+    ("Phagun", NanakshahiMonth::Phaggan),
+    ("Phalgun", NanakshahiMonth::Phaggan),
+    ("ਚੇਤਰ", NanakshahiMonth::Chet),
+    ("ਵਿਸਾਖ", NanakshahiMonth::Vaisakh),
+    ("ਵਸਾਖ", NanakshahiMonth::Vaisakh),
+    ("ਹਾੜ੍ਹ", NanakshahiMonth::Harh),
+    ("ਸਾਉਣ", NanakshahiMonth::Sawan),
+    ("ਭਾਦੋ", NanakshahiMonth::Bhadon),
+    ("ਅਸੂ", NanakshahiMonth::Assu),
+    ("ਕਤਕ", NanakshahiMonth::Kattak),
+    ("ਕੱਤਿਕ", NanakshahiMonth::Kattak),
+    ("ਮਘਰ", NanakshahiMonth::Maghar),
+    ("ਫਗਣ", NanakshahiMonth::Phaggan),
+];
+
+/// Fields collected while parsing.
+#[derive(Debug, Default)]
+struct Parsed {
+    year: Option<i32>,
+    month: Option<NanakshahiMonth>,
+    day: Option<u8>,
+    ordinal: Option<u16>,
+    weekday: Option<NanakshahiWeekday>,
+    era_year: Option<i32>,
+    nanakshahi_era: Option<bool>,
+}
+
+fn set<T: PartialEq>(field: &mut Option<T>, value: T, position: usize) -> Result<(), ParseError> {
+    match field {
+        Some(existing) if *existing != value => {
+            Err(ParseError::new(position, ParseErrorKind::Impossible))
+        }
+        _ => {
+            *field = Some(value);
+            Ok(())
+        }
+    }
+}
+
+struct Parser<'a> {
+    input: &'a str,
+    position: usize,
+    parsed: Parsed,
+}
+
+impl<'a> Parser<'a> {
+    fn remainder(&self) -> &'a str {
+        &self.input[self.position..]
+    }
+
+    fn error(&self, kind: ParseErrorKind) -> ParseError {
+        ParseError::new(self.position, kind)
+    }
+
+    /// An error for input that does not match, or ends too soon.
+    fn mismatch(&self) -> ParseError {
+        if self.remainder().is_empty() {
+            self.error(ParseErrorKind::TooShort)
+        } else {
+            self.error(ParseErrorKind::Invalid)
+        }
+    }
+
+    fn skip_whitespace(&mut self) {
+        let remainder: &str = self.remainder();
+        self.position += remainder.len() - remainder.trim_start().len();
+    }
+
+    fn parse_pattern(&mut self, pattern: &str) -> Result<(), ParseError> {
+        for item in StrftimeItems::new(pattern) {
+            match item {
+                Item::Literal(literal) => self.parse_literal(literal)?,
+                Item::Specifier { spec, pad } => self.parse_specifier(spec, pad)?,
+                Item::Invalid => return Err(self.error(ParseErrorKind::BadFormat)),
+            }
+        }
+        Ok(())
+    }
+
+    fn parse_literal(&mut self, literal: &str) -> Result<(), ParseError> {
+        for expected in literal.chars() {
+            if expected.is_whitespace() {
+                self.skip_whitespace();
+            } else if self.remainder().starts_with(expected) {
+                self.position += expected.len_utf8();
+            } else {
+                return Err(self.mismatch());
+            }
+        }
+        Ok(())
+    }
+
+    fn parse_specifier(&mut self, spec: char, pad: Option<Pad>) -> Result<(), ParseError> {
+        if spec == 'e' || pad == Some(Pad::Space) {
+            self.skip_whitespace();
+        }
+
+        let start: usize = self.position;
+        match spec {
+            'Y' => {
+                let negative: bool = self.remainder().starts_with('-');
+                if negative {
+                    self.position += 1;
+                }
+                let year: i32 = self.parse_number(6)? as i32;
+                set(
+                    &mut self.parsed.year,
+                    if negative { -year } else { year },
+                    start,
+                )
+            }
+            'N' => {
+                let year: u64 = self.parse_number(6)?;
+                set(&mut self.parsed.era_year, year as i32, start)
+            }
+            'm' => {
+                let month: u64 = self.parse_number(2)?;
+                let month: NanakshahiMonth = NanakshahiMonth::try_from(month as u8)
+                    .map_err(|error| ParseError::new(start, ParseErrorKind::OutOfRange(error)))?;
+                set(&mut self.parsed.month, month, start)
+            }
+            'd' | 'e' => {
+                let day: u64 = self.parse_number(2)?;
+                set(&mut self.parsed.day, day as u8, start)
+            }
+            'j' => {
+                let ordinal: u64 = self.parse_number(3)?;
+                set(&mut self.parsed.ordinal, ordinal as u16, start)
+            }
+            'w' => {
+                let weekday: u64 = self.parse_number(1)?;
+                let weekday: NanakshahiWeekday = *NanakshahiWeekday::ALL
+                    .get(weekday as usize)
+                    .ok_or(ParseError::new(start, ParseErrorKind::Invalid))?;
+                set(&mut self.parsed.weekday, weekday, start)
+            }
+            _ if pad.is_some() => Err(self.error(ParseErrorKind::BadFormat)),
+            'B' | 'b' => {
+                let month: NanakshahiMonth = self.parse_name(month_names())?;
+                set(&mut self.parsed.month, month, start)
+            }
+            'A' | 'a' => {
+                let weekday: NanakshahiWeekday = self.parse_name(weekday_names())?;
+                set(&mut self.parsed.weekday, weekday, start)
+            }
+            'E' => {
+                let nanakshahi: bool = self.parse_name(era_names())?;
+                set(&mut self.parsed.nanakshahi_era, nanakshahi, start)
+            }
+            'F' => self.parse_pattern("%Y-%m-%d"),
+            _ => Err(self.error(ParseErrorKind::BadFormat)),
+        }
+    }
+
+    /// Parse up to `max_digits` digits written in any supported script.
+    fn parse_number(&mut self, max_digits: usize) -> Result<u64, ParseError> {
+        let mut value: u64 = 0;
+        let mut digits: usize = 0;
+        for character in self.remainder().chars().take(max_digits) {
+            let Some(digit) = Script::ALL
+                .iter()
+                .find_map(|script| script.digit_value(character))
+            else {
+                break;
+            };
+            value = value * 10 + digit as u64;
+            digits += 1;
+            self.position += character.len_utf8();
+        }
+
+        if digits == 0 {
+            return Err(self.mismatch());
+        }
+        Ok(value)
+    }
+
+    /// Match the longest name at the current position, ignoring ASCII case.
+    fn parse_name<T>(&mut self, names: Vec<(&str, T)>) -> Result<T, ParseError> {
+        let remainder: &str = self.remainder();
+        let (name, value) = names
+            .into_iter()
+            .filter(|(name, _)| {
+                remainder
+                    .get(..name.len())
+                    .is_some_and(|prefix| prefix.eq_ignore_ascii_case(name))
+            })
+            .max_by_key(|(name, _)| name.len())
+            .ok_or_else(|| self.mismatch())?;
+
+        self.position += name.len();
+        Ok(value)
+    }
+
+    /// The parsed year, checked against the era if one was also parsed.
+    fn year(&self) -> Result<i32, ParseError> {
+        let parsed: &Parsed = &self.parsed;
+        let era_year: Option<i32> = parsed.era_year.map(|year| {
+            if parsed.nanakshahi_era == Some(false) {
+                1 - year
+            } else {
+                year
+            }
+        });
+        let impossible: bool = match (parsed.year, era_year, parsed.nanakshahi_era) {
+            (Some(year), Some(era_year), _) => year != era_year,
+            (Some(year), None, Some(nanakshahi)) => (year >= 1) != nanakshahi,
+            _ => false,
+        };
+        if impossible {
+            return Err(ParseError::new(self.position, ParseErrorKind::Impossible));
+        }
+        parsed
+            .year
+            .or(era_year)
+            .ok_or(ParseError::new(self.position, ParseErrorKind::NotEnough))
+    }
+
+    fn finish(self) -> Result<NanakshahiDate, ParseError> {
+        if !self.remainder().is_empty() {
+            return Err(self.error(ParseErrorKind::TooLong));
+        }
+
+        let year: i32 = self.year()?;
+        let parsed: &Parsed = &self.parsed;
+        let out_of_range =
+            |error| ParseError::new(self.position, ParseErrorKind::OutOfRange(error));
+
+        let date: NanakshahiDate = match (parsed.month, parsed.day, parsed.ordinal) {
+            (Some(month), Some(day), _) => {
+                let date: NanakshahiDate =
+                    NanakshahiDate::new(year, month, day).map_err(out_of_range)?;
+                if parsed
+                    .ordinal
+                    .is_some_and(|ordinal| ordinal as u32 != date.ordinal())
+                {
+                    return Err(ParseError::new(self.position, ParseErrorKind::Impossible));
+                }
+                date
+            }
+            (_, _, Some(ordinal)) => {
+                let date: NanakshahiDate =
+                    NanakshahiDate::new(year, NanakshahiMonth::Chet, 1).map_err(out_of_range)?;
+                let date: Option<NanakshahiDate> = ordinal
+                    .checked_sub(1)
+                    .and_then(|days| date.checked_add_days(days as u64))
+                    .filter(|date| date.year == year);
+                let date: NanakshahiDate =
+                    date.ok_or(ParseError::new(self.position, ParseErrorKind::Invalid))?;
+                if parsed.month.is_some_and(|month| month != date.month) {
+                    return Err(ParseError::new(self.position, ParseErrorKind::Impossible));
+                }
+                date
+            }
+            _ => return Err(ParseError::new(self.position, ParseErrorKind::NotEnough)),
+        };
+
+        if parsed
+            .weekday
+            .is_some_and(|weekday| weekday != date.weekday())
+        {
+            return Err(ParseError::new(self.position, ParseErrorKind::Impossible));
+        }
+        Ok(date)
+    }
+
+    /// Finish with a date under a calendar variant, which needs a month and
+    /// day rather than a day of the year.
+    fn finish_in(self, variant: CalendarVariant) -> Result<VariantDate, ParseError> {
+        if !self.remainder().is_empty() {
+            return Err(self.error(ParseErrorKind::TooLong));
+        }
+
+        let year: i32 = self.year()?;
+        let parsed: &Parsed = &self.parsed;
+        let (Some(month), Some(day)) = (parsed.month, parsed.day) else {
+            return Err(self.error(ParseErrorKind::NotEnough));
+        };
+        let out_of_range =
+            |error| ParseError::new(self.position, ParseErrorKind::OutOfRange(error));
+        let gregorian: NaiveDate = variant
+            .from(year, month.number(), day)
+            .map_err(out_of_range)?;
+        let date: VariantDate = variant
+            .to(
+                gregorian.year(),
+                gregorian.month() as u8,
+                gregorian.day() as u8,
+            )
+            .map_err(out_of_range)?;
+
+        let impossible: bool = parsed
+            .ordinal
+            .is_some_and(|ordinal| ordinal as u32 != date.ordinal())
+            || parsed
+                .weekday
+                .is_some_and(|weekday| weekday != date.weekday());
+        if impossible {
+            return Err(self.error(ParseErrorKind::Impossible));
+        }
+        Ok(date)
+    }
+}
+
+fn month_names() -> Vec<(&'static str, NanakshahiMonth)> {
+    let mut names: Vec<(&str, NanakshahiMonth)> = NANAKSHAHI_MONTH_SPELLINGS.to_vec();
+    for month in NanakshahiMonth::ALL {
+        for script in Script::ALL {
+            names.push((month.name_in(script), month));
+            names.push((short_month_name(month, script), month));
+        }
+    }
+    names
+}
+
+/// The month with a name in any script or spelling, ignoring ASCII case.
+pub(crate) fn month_from_name(name: &str) -> Option<NanakshahiMonth> {
+    month_names()
+        .into_iter()
+        .find(|(candidate, _)| candidate.eq_ignore_ascii_case(name))
+        .map(|(_, month)| month)
+}
+
+fn weekday_names() -> Vec<(&'static str, NanakshahiWeekday)> {
+    let mut names: Vec<(&str, NanakshahiWeekday)> = Vec::new();
+    for weekday in NanakshahiWeekday::ALL {
+        names.push((weekday.english_name(), weekday));
+        names.push((&weekday.english_name()[..3], weekday));
+        for script in Script::ALL {
+            names.push((weekday.name_in(script), weekday));
+            names.push((short_weekday_name(weekday, script), weekday));
+        }
+    }
+    names
+}
+
+fn era_names() -> Vec<(&'static str, bool)> {
+    let mut names: Vec<(&str, bool)> = vec![("N.S.", true), ("B.N.S.", false)];
+    for script in Script::ALL {
+        names.push((era(script, true), true));
+        names.push((era(script, false), false));
+    }
+    names
+}
+
+/// Parse `input` according to a `strftime`-style `pattern`.
+pub(crate) fn parse(input: &str, pattern: &str) -> Result<NanakshahiDate, ParseError> {
+    parse_with(input, pattern, |parser: Parser| parser.finish())
+}
+
+fn parse_with<T>(
+    input: &str,
+    pattern: &str,
+    finish: impl Fn(Parser) -> Result<T, ParseError>,
+) -> Result<T, ParseError> {
+    let mut parser: Parser = Parser {
+        input,
+        position: 0,
+        parsed: Parsed::default(),
+    };
+    parser.parse_pattern(pattern)?;
+    finish(parser)
+}
+
+/// Parse `input` with each of [`FROM_STR_PATTERNS`], returning the error that
+/// got furthest if none match.
+pub(crate) fn parse_any(input: &str) -> Result<NanakshahiDate, ParseError> {
+    parse_any_with(input, |parser: Parser| parser.finish())
+}
+
+/// Parse `input` as a date under a calendar variant, like [`parse_any`].
+pub(crate) fn parse_any_in(
+    input: &str,
+    variant: CalendarVariant,
+) -> Result<VariantDate, ParseError> {
+    parse_any_with(input, |parser: Parser| parser.finish_in(variant))
+}
+
+fn parse_any_with<T>(
+    input: &str,
+    finish: impl Fn(Parser) -> Result<T, ParseError>,
+) -> Result<T, ParseError> {
+    let trimmed: &str = input.trim();
+    let offset: usize = input.len() - input.trim_start().len();
+
+    let mut furthest: Option<ParseError> = None;
+    for pattern in FROM_STR_PATTERNS {
+        match parse_with(trimmed, pattern, &finish) {
+            Ok(date) => return Ok(date),
+            Err(error) => {
+                if furthest.is_none_or(|furthest| error.position() > furthest.position()) {
+                    furthest = Some(error);
+                }
+            }
+        }
+    }
+
+    let error: ParseError = furthest.expect("No patterns to parse with");
+    Err(ParseError::new(error.position() + offset, error.kind()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::NanakshahiError;
+
+    fn date(year: i32, month: NanakshahiMonth, day: u8) -> NanakshahiDate {
+        NanakshahiDate::new(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn test_parse_numeric() {
+        assert_eq!(
+            "557-01-01".parse::<NanakshahiDate>(),
+            Ok(date(557, NanakshahiMonth::Chet, 1))
+        );
+        assert_eq!(
+            parse("557/2/8", "%Y/%m/%d"),
+            Ok(date(557, NanakshahiMonth::Vaisakh, 8))
+        );
+        assert_eq!(
+            parse("557 039", "%Y %j"),
+            Ok(date(557, NanakshahiMonth::Vaisakh, 8))
+        );
+    }
+
+    #[test]
+    fn test_parse_spelling_variants() {
+        for text in [
+            "1 Vaisakh 557",
+            "1 Visakh 557",
+            "1 baisakh 557",
+            "1 Vai 557",
+        ] {
+            assert_eq!(
+                text.parse::<NanakshahiDate>(),
+                Ok(date(557, NanakshahiMonth::Vaisakh, 1)),
+                "{text}"
+            );
+        }
+        assert_eq!(
+            "Haar 5, 557".parse::<NanakshahiDate>(),
+            Ok(date(557, NanakshahiMonth::Harh, 5))
+        );
+        assert_eq!(
+            "10 Katak 557 NS".parse::<NanakshahiDate>(),
+            Ok(date(557, NanakshahiMonth::Kattak, 10))
+        );
+        assert_eq!(
+            "1 Magh 557".parse::<NanakshahiDate>(),
+            Ok(date(557, NanakshahiMonth::Magh, 1))
+        );
+        assert_eq!(
+            "1 Maghar 557".parse::<NanakshahiDate>(),
+            Ok(date(557, NanakshahiMonth::Maghar, 1))
+        );
+    }
+
+    #[test]
+    fn test_parse_gurmukhi() {
+        assert_eq!(
+            "ਚੇਤ ੧, ੫੫੭".parse::<NanakshahiDate>(),
+            Ok(date(557, NanakshahiMonth::Chet, 1))
+        );
+        assert_eq!(
+            "੧੩ ਹਾੜ੍ਹ ੫੫੭".parse::<NanakshahiDate>(),
+            Ok(date(557, NanakshahiMonth::Harh, 13))
+        );
+    }
+
+    #[test]
+    fn test_parse_in_variant() {
+        let date = parse_any_in("32 Sawan 557", CalendarVariant::Bikrami).unwrap();
+        assert_eq!(date.to_string(), "557-05-32");
+        assert_eq!(
+            parse_any_in("557-05-32", CalendarVariant::Original2003).map_err(|error| error.kind()),
+            Err(ParseErrorKind::OutOfRange(NanakshahiError::InvalidDay {
+                year: 557,
+                month: 5,
+                day: 32
+            }))
+        );
+        assert_eq!(
+            parse_any_in("557-05", CalendarVariant::Amended2010).map_err(|error| error.kind()),
+            Err(ParseErrorKind::TooShort)
+        );
+    }
+
+    #[test]
+    fn test_parse_before_epoch() {
+        assert_eq!(
+            "-4-01-01".parse::<NanakshahiDate>(),
+            Ok(date(-4, NanakshahiMonth::Chet, 1))
+        );
+        assert_eq!(
+            "1 Chet 5 BNS".parse::<NanakshahiDate>(),
+            Ok(date(-4, NanakshahiMonth::Chet, 1))
+        );
+        assert_eq!(
+            "1 Chet 5 NS".parse::<NanakshahiDate>(),
+            Ok(date(5, NanakshahiMonth::Chet, 1))
+        );
+        assert_eq!(
+            parse("5 BNS", "%Y %E").map_err(|error| error.kind()),
+            Err(ParseErrorKind::Impossible)
+        );
+    }
+
+    #[test]
+    fn test_parse_weekday() {
+        assert_eq!(
+            parse("Shukarvar, 1 Chet 557", "%A, %d %B %Y"),
+            Ok(date(557, NanakshahiMonth::Chet, 1))
+        );
+        assert_eq!(
+            parse("Somvar, 1 Chet 557", "%A, %d %B %Y"),
+            Err(ParseError::new(18, ParseErrorKind::Impossible))
+        );
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(
+            "557-13-01".parse::<NanakshahiDate>(),
+            Err(ParseError::new(
+                4,
+                ParseErrorKind::OutOfRange(NanakshahiError::InvalidMonth(13))
+            ))
+        );
+        assert_eq!(
+            "1 Chat 557".parse::<NanakshahiDate>(),
+            Err(ParseError::new(2, ParseErrorKind::Invalid))
+        );
+        assert_eq!(
+            parse("557-01", "%F"),
+            Err(ParseError::new(6, ParseErrorKind::TooShort))
+        );
+        assert_eq!(
+            parse("557-01-01 NS", "%F"),
+            Err(ParseError::new(9, ParseErrorKind::TooLong))
+        );
+        assert_eq!(
+            parse("557", "%Y"),
+            Err(ParseError::new(3, ParseErrorKind::NotEnough))
+        );
+        assert_eq!(
+            parse("557", "%Q"),
+            Err(ParseError::new(0, ParseErrorKind::BadFormat))
+        );
+        assert!(matches!(
+            parse("556-12-31", "%F").map_err(|error| error.kind()),
+            Err(ParseErrorKind::OutOfRange(
+                NanakshahiError::InvalidDay { .. }
+            ))
+        ));
+    }
+
+    #[test]
+    fn test_format_round_trip() {
+        let date = date(557, NanakshahiMonth::Maghar, 21);
+
+        for script in Script::ALL {
+            for pattern in ["%F", "%d %B %Y", "%A %e %b %N %E", "%Y %j"] {
+                let text = date.format_in(pattern, script).to_string();
+                assert_eq!(parse(&text, pattern), Ok(date), "{text}");
+            }
+        }
+    }
+}
@@ -0,0 +1,174 @@
+//! Sangrand, the first day of each solar month.
+//!
+//! The Nanakshahi calendar fixes Sangrand to the first of each month, while
+//! the Bikrami solar calendar begins each month at the sankranti, the instant
+//! the sun enters the next rashi of the sidereal zodiac. Communities differ on
+//! which to follow, so [`SangrandSystem::Sidereal`] gives the sankranti
+//! alongside the fixed date.
+
+use chrono::{DateTime, FixedOffset, NaiveDate, NaiveTime, Utc};
+
+use crate::{astro, Ayanamsa, NanakshahiDate, NanakshahiError, NanakshahiMonth, Rashi};
+
+/// How the start of each solar month is reckoned.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum SangrandSystem {
+    /// The fixed first of the month in the Nanakshahi calendar only.
+    #[default]
+    Nanakshahi,
+    /// The fixed date together with the sidereal sankranti under the
+    /// ayanamsa.
+    Sidereal(Ayanamsa),
+}
+
+/// The start of a solar month.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Sangrand {
+    /// The first of the month in the Nanakshahi calendar.
+    pub date: NanakshahiDate,
+    pub gregorian: NaiveDate,
+    /// The rashi the sun enters at the start of the month.
+    pub rashi: Rashi,
+    /// The instant the sun enters the rashi, under
+    /// [`SangrandSystem::Sidereal`].
+    pub sankranti: Option<DateTime<Utc>>,
+}
+
+impl Sangrand {
+    /// The date of the sankranti in a time zone, if it was computed.
+    pub fn sankranti_date(&self, offset: FixedOffset) -> Option<NaiveDate> {
+        self.sankranti
+            .map(|instant| instant.with_timezone(&offset).date_naive())
+    }
+}
+
+/// The Sangrand of a month in a Nanakshahi year.
+///
+/// # Errors
+/// Returns [`NanakshahiError::YearOutOfRange`] if the year cannot be
+/// converted.
+///
+/// # Examples
+/// ```
+/// use chrono::{FixedOffset, NaiveDate};
+/// use nanakshahi::sangrand::{self, SangrandSystem};
+/// use nanakshahi::{Ayanamsa, NanakshahiMonth, Rashi};
+///
+/// let system = SangrandSystem::Sidereal(Ayanamsa::Lahiri);
+/// let vaisakhi = sangrand::sangrand(557, NanakshahiMonth::Vaisakh, system)?;
+/// assert_eq!(vaisakhi.rashi, Rashi::Mesh);
+/// assert_eq!(vaisakhi.gregorian, NaiveDate::from_ymd_opt(2025, 4, 14).unwrap());
+///
+/// let ist = FixedOffset::east_opt(19800).unwrap();
+/// assert_eq!(
+///     vaisakhi.sankranti_date(ist),
+///     NaiveDate::from_ymd_opt(2025, 4, 14)
+/// );
+/// # Ok::<(), nanakshahi::NanakshahiError>(())
+/// ```
+pub fn sangrand(
+    year: i32,
+    month: NanakshahiMonth,
+    system: SangrandSystem,
+) -> Result<Sangrand, NanakshahiError> {
+    let date: NanakshahiDate = NanakshahiDate::new(year, month, 1)?;
+    let gregorian: NaiveDate = date.to_gregorian()?;
+    let rashi: Rashi = Rashi::ALL[(month as usize + 10) % 12];
+
+    let sankranti: Option<DateTime<Utc>> = match system {
+        SangrandSystem::Nanakshahi => None,
+        SangrandSystem::Sidereal(ayanamsa) => {
+            let estimate: f64 = astro::julian_day_at(gregorian, NaiveTime::MIN);
+            let instant = astro::from_julian_day(ayanamsa.ingress(rashi, estimate));
+            Some(instant.ok_or(NanakshahiError::YearOutOfRange(year))?)
+        }
+    };
+
+    Ok(Sangrand {
+        date,
+        gregorian,
+        rashi,
+        sankranti,
+    })
+}
+
+/// The Sangrand of every month in a Nanakshahi year.
+///
+/// # Errors
+/// See [`sangrand`].
+pub fn sangrands_in_year(
+    year: i32,
+    system: SangrandSystem,
+) -> Result<Vec<Sangrand>, NanakshahiError> {
+    NanakshahiMonth::ALL
+        .iter()
+        .map(|&month| sangrand(year, month, system))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn assert_near(instant: Option<DateTime<Utc>>, expected: DateTime<Utc>) {
+        let difference = (instant.unwrap() - expected).num_minutes().abs();
+        assert!(difference <= 15, "{instant:?} is not near {expected}");
+    }
+
+    #[test]
+    fn test_fixed_sangrand() {
+        let sangrand = sangrand(556, NanakshahiMonth::Magh, SangrandSystem::Nanakshahi).unwrap();
+
+        assert_eq!(
+            sangrand.gregorian,
+            NaiveDate::from_ymd_opt(2025, 1, 13).unwrap()
+        );
+        assert_eq!(sangrand.rashi, Rashi::Makar);
+        assert_eq!(sangrand.sankranti, None);
+    }
+
+    #[test]
+    fn test_sidereal_sankranti() {
+        let system = SangrandSystem::Sidereal(Ayanamsa::Lahiri);
+
+        // Makar Sankranti on 14 January 2025 at 09:03 IST.
+        let magh = sangrand(556, NanakshahiMonth::Magh, system).unwrap();
+        assert_near(
+            magh.sankranti,
+            Utc.with_ymd_and_hms(2025, 1, 14, 3, 33, 0).unwrap(),
+        );
+
+        // Mesh Sankranti on 14 April 2025 at 03:30 IST.
+        let vaisakh = sangrand(557, NanakshahiMonth::Vaisakh, system).unwrap();
+        assert_near(
+            vaisakh.sankranti,
+            Utc.with_ymd_and_hms(2025, 4, 13, 22, 0, 0).unwrap(),
+        );
+
+        // A smaller ayanamsa puts the sun into the rashi about a day and a half earlier.
+        let raman = sangrand(
+            557,
+            NanakshahiMonth::Vaisakh,
+            SangrandSystem::Sidereal(Ayanamsa::Raman),
+        )
+        .unwrap();
+        let hours = (vaisakh.sankranti.unwrap() - raman.sankranti.unwrap()).num_hours();
+        assert!((30..40).contains(&hours), "{hours}");
+    }
+
+    #[test]
+    fn test_sangrands_in_year() {
+        let sangrands = sangrands_in_year(557, SangrandSystem::Sidereal(Ayanamsa::Lahiri)).unwrap();
+
+        assert_eq!(sangrands.len(), 12);
+        assert!(sangrands
+            .windows(2)
+            .all(|pair| pair[0].sankranti < pair[1].sankranti));
+        for sangrand in &sangrands {
+            let offset = FixedOffset::east_opt(19800).unwrap();
+            let drift = (sangrand.sankranti_date(offset).unwrap() - sangrand.gregorian).num_days();
+            assert!(drift.abs() <= 3, "{sangrand:?}");
+        }
+    }
+}
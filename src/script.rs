@@ -0,0 +1,88 @@
+/// The script used to write month names, weekday names and numerals.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub enum Script {
+    /// Romanized names with Western digits, such as "1 Chet 557".
+    #[default]
+    Latin,
+    /// Gurmukhi names and digits, such as "੧ ਚੇਤ ੫੫੭".
+    Gurmukhi,
+    /// Shahmukhi names with Urdu digits, such as "۱ چیت ۵۵۷".
+    Shahmukhi,
+    /// Devanagari names and digits, such as "१ चेत ५५७".
+    Devanagari,
+}
+
+impl Script {
+    /// All supported scripts.
+    pub const ALL: [Script; 4] = [
+        Script::Latin,
+        Script::Gurmukhi,
+        Script::Shahmukhi,
+        Script::Devanagari,
+    ];
+
+    /// The digits 0 to 9 in this script.
+    pub fn digits(self) -> [char; 10] {
+        let zero: u32 = match self {
+            Script::Latin => '0' as u32,
+            Script::Gurmukhi => '੦' as u32,
+            Script::Shahmukhi => '۰' as u32,
+            Script::Devanagari => '०' as u32,
+        };
+
+        let mut digits: [char; 10] = ['0'; 10];
+        for (index, digit) in digits.iter_mut().enumerate() {
+            *digit = char::from_u32(zero + index as u32).expect("Invalid digit");
+        }
+        digits
+    }
+
+    /// Write a number using the digits of this script.
+    ///
+    /// # Examples
+    /// ```
+    /// use nanakshahi::Script;
+    ///
+    /// assert_eq!(Script::Latin.numeral(557), "557");
+    /// assert_eq!(Script::Gurmukhi.numeral(557), "੫੫੭");
+    /// ```
+    pub fn numeral(self, number: u64) -> String {
+        let digits: [char; 10] = self.digits();
+        number
+            .to_string()
+            .chars()
+            .map(|digit| digits[digit as usize - '0' as usize])
+            .collect()
+    }
+
+    /// The value of a digit written in this script, if it is one.
+    pub fn digit_value(self, digit: char) -> Option<u8> {
+        self.digits()
+            .iter()
+            .position(|&candidate| candidate == digit)
+            .map(|value| value as u8)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_numeral() {
+        assert_eq!(Script::Latin.numeral(0), "0");
+        assert_eq!(Script::Gurmukhi.numeral(1469), "੧੪੬੯");
+        assert_eq!(Script::Shahmukhi.numeral(1469), "۱۴۶۹");
+        assert_eq!(Script::Devanagari.numeral(1469), "१४६९");
+    }
+
+    #[test]
+    fn test_digit_value() {
+        for script in Script::ALL {
+            for (value, digit) in script.digits().into_iter().enumerate() {
+                assert_eq!(script.digit_value(digit), Some(value as u8));
+            }
+        }
+        assert_eq!(Script::Gurmukhi.digit_value('7'), None);
+    }
+}
@@ -0,0 +1,246 @@
+//! Serialization with serde, enabled by the `serde` feature.
+//!
+//! [`NanakshahiDate`] serializes as a string such as "557-01-01", and
+//! [`NanakshahiMonth`] as its romanized name. A month is read back from its
+//! name in any script or from its number.
+//!
+//! The modules here select another representation for a date field with
+//! `#[serde(with = "...")]`:
+//!
+//! - [`iso`], the string "557-01-01", as by default;
+//! - [`parts`], a map such as `{"year": 557, "month": "Chet", "day": 1}`;
+//! - [`rata_die`], the day number from [`NanakshahiDate::to_rata_die`].
+//!
+//! # Examples
+//! ```
+//! use nanakshahi::NanakshahiDate;
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Event {
+//!     date: NanakshahiDate,
+//!     #[serde(with = "nanakshahi::serde::parts")]
+//!     parts: NanakshahiDate,
+//!     #[serde(with = "nanakshahi::serde::rata_die")]
+//!     day: NanakshahiDate,
+//! }
+//!
+//! let date: NanakshahiDate = "557-01-01".parse()?;
+//! let json = serde_json::to_string(&Event { date, parts: date, day: date }).unwrap();
+//! assert_eq!(
+//!     json,
+//!     r#"{"date":"557-01-01","parts":{"year":557,"month":"Chet","day":1},"day":739324}"#
+//! );
+//! # Ok::<(), nanakshahi::ParseError>(())
+//! ```
+
+use std::fmt;
+
+use ::serde::de::{self, Deserializer, Visitor};
+use ::serde::ser::Serializer;
+use ::serde::{Deserialize, Serialize};
+
+use crate::parse;
+use crate::{NanakshahiDate, NanakshahiMonth};
+
+impl Serialize for NanakshahiDate {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        iso::serialize(self, serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for NanakshahiDate {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        iso::deserialize(deserializer)
+    }
+}
+
+impl Serialize for NanakshahiMonth {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.name())
+    }
+}
+
+impl<'de> Deserialize<'de> for NanakshahiMonth {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_any(MonthVisitor)
+    }
+}
+
+struct MonthVisitor;
+
+impl Visitor<'_> for MonthVisitor {
+    type Value = NanakshahiMonth;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a Nanakshahi month name or a number from 1 to 12")
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
+        parse::month_from_name(value)
+            .ok_or_else(|| E::invalid_value(de::Unexpected::Str(value), &self))
+    }
+
+    fn visit_u64<E: de::Error>(self, value: u64) -> Result<Self::Value, E> {
+        u8::try_from(value)
+            .ok()
+            .and_then(|month| NanakshahiMonth::try_from(month).ok())
+            .ok_or_else(|| E::invalid_value(de::Unexpected::Unsigned(value), &self))
+    }
+
+    fn visit_i64<E: de::Error>(self, value: i64) -> Result<Self::Value, E> {
+        u64::try_from(value)
+            .map_err(|_| E::invalid_value(de::Unexpected::Signed(value), &self))
+            .and_then(|value| self.visit_u64(value))
+    }
+}
+
+/// A date as a string such as "557-01-01".
+pub mod iso {
+    use super::*;
+
+    /// Serialize a date as a string.
+    pub fn serialize<S: Serializer>(
+        date: &NanakshahiDate,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(date)
+    }
+
+    /// Deserialize a date from a string in any format accepted by
+    /// [`str::parse`].
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<NanakshahiDate, D::Error> {
+        deserializer.deserialize_str(IsoVisitor)
+    }
+
+    struct IsoVisitor;
+
+    impl Visitor<'_> for IsoVisitor {
+        type Value = NanakshahiDate;
+
+        fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str("a Nanakshahi date such as \"557-01-01\"")
+        }
+
+        fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
+            value.parse().map_err(E::custom)
+        }
+    }
+}
+
+/// A date as a map of its year, month name and day.
+pub mod parts {
+    use super::*;
+
+    #[derive(Serialize, Deserialize)]
+    struct Parts {
+        year: i32,
+        month: NanakshahiMonth,
+        day: u8,
+    }
+
+    /// Serialize a date as a map.
+    pub fn serialize<S: Serializer>(
+        date: &NanakshahiDate,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        Parts {
+            year: date.year,
+            month: date.month,
+            day: date.day,
+        }
+        .serialize(serializer)
+    }
+
+    /// Deserialize a date from a map, checking that the day exists.
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<NanakshahiDate, D::Error> {
+        let parts: Parts = Parts::deserialize(deserializer)?;
+        NanakshahiDate::new(parts.year, parts.month, parts.day).map_err(de::Error::custom)
+    }
+}
+
+/// A date as its Rata Die, the number of days from 1 January of year 1 in
+/// the proleptic Gregorian calendar.
+pub mod rata_die {
+    use super::*;
+
+    /// Serialize a date as an integer.
+    pub fn serialize<S: Serializer>(
+        date: &NanakshahiDate,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.serialize_i64(date.to_rata_die())
+    }
+
+    /// Deserialize a date from an integer.
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<NanakshahiDate, D::Error> {
+        let rata_die: i64 = i64::deserialize(deserializer)?;
+        NanakshahiDate::from_rata_die(rata_die).ok_or_else(|| {
+            de::Error::invalid_value(de::Unexpected::Signed(rata_die), &"a Rata Die in range")
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Record {
+        #[serde(with = "crate::serde::parts")]
+        parts: NanakshahiDate,
+        #[serde(with = "crate::serde::rata_die")]
+        day: NanakshahiDate,
+    }
+
+    fn date(year: i32, month: NanakshahiMonth, day: u8) -> NanakshahiDate {
+        NanakshahiDate::new(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn test_date_round_trip() {
+        let date = date(-5, NanakshahiMonth::Phaggan, 30);
+        let json = serde_json::to_string(&date).unwrap();
+
+        assert_eq!(json, "\"-5-12-30\"");
+        assert_eq!(serde_json::from_str::<NanakshahiDate>(&json).unwrap(), date);
+
+        let record = Record {
+            parts: date,
+            day: date,
+        };
+        let json = serde_json::to_string(&record).unwrap();
+        assert_eq!(serde_json::from_str::<Record>(&json).unwrap(), record);
+    }
+
+    #[test]
+    fn test_month() {
+        assert_eq!(
+            serde_json::to_string(&NanakshahiMonth::Vaisakh).unwrap(),
+            "\"Vaisakh\""
+        );
+        for json in ["\"Vaisakh\"", "\"vaisakh\"", "\"ਵੈਸਾਖ\"", "2"] {
+            assert_eq!(
+                serde_json::from_str::<NanakshahiMonth>(json).unwrap(),
+                NanakshahiMonth::Vaisakh
+            );
+        }
+        assert!(serde_json::from_str::<NanakshahiMonth>("13").is_err());
+        assert!(serde_json::from_str::<NanakshahiMonth>("\"Vaisakhi\"").is_err());
+    }
+
+    #[test]
+    fn test_invalid_dates() {
+        assert!(serde_json::from_str::<NanakshahiDate>("\"556-12-31\"").is_err());
+
+        let json = r#"{"parts":{"year":556,"month":"Phaggan","day":31},"day":0}"#;
+        let error = serde_json::from_str::<Record>(json).unwrap_err();
+        assert!(error.to_string().contains("invalid day 31"), "{error}");
+    }
+}
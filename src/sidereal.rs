@@ -0,0 +1,227 @@
+use std::fmt;
+
+use chrono::{DateTime, Utc};
+
+use crate::{astro, Script};
+
+/// The time the sun takes to return to the same sidereal longitude, in days.
+const SIDEREAL_YEAR: f64 = 365.256363;
+
+/// The offset between the tropical and sidereal zodiacs used to place the
+/// sun in a rashi.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Ayanamsa {
+    /// The Chitrapaksha ayanamsa adopted by the Government of India, used by
+    /// most Punjabi jantris.
+    #[default]
+    Lahiri,
+    /// The ayanamsa of B. V. Raman.
+    Raman,
+    /// The ayanamsa of K. S. Krishnamurti.
+    Krishnamurti,
+}
+
+impl Ayanamsa {
+    /// The ayanamsa at an instant, in degrees.
+    ///
+    /// # Examples
+    /// ```
+    /// use chrono::{TimeZone, Utc};
+    /// use nanakshahi::Ayanamsa;
+    ///
+    /// let instant = Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap();
+    /// let degrees = Ayanamsa::Lahiri.degrees(instant);
+    /// assert!((degrees - 24.21).abs() < 0.01);
+    /// ```
+    pub fn degrees(self, instant: DateTime<Utc>) -> f64 {
+        self.degrees_at(astro::julian_day(instant))
+    }
+
+    pub(crate) fn degrees_at(self, julian_day: f64) -> f64 {
+        let at_j2000: f64 = match self {
+            // 23°15'00.658" on 21 March 1956, as defined by the Calendar
+            // Reform Committee.
+            Ayanamsa::Lahiri => 23.8618,
+            Ayanamsa::Raman => 22.411,
+            Ayanamsa::Krishnamurti => 23.760,
+        };
+        at_j2000 + astro::precession(julian_day)
+    }
+
+    /// The sun's sidereal longitude under this ayanamsa, in degrees.
+    pub(crate) fn sun_longitude(self, julian_day: f64) -> f64 {
+        astro::normalize(astro::sun_longitude(julian_day) - self.degrees_at(julian_day))
+    }
+
+    /// The instant nearest to `estimate` at which the sun enters a rashi.
+    pub(crate) fn ingress(self, rashi: Rashi, estimate: f64) -> f64 {
+        astro::angle_time(
+            |julian_day| self.sun_longitude(julian_day),
+            rashi.longitude(),
+            360.0 / SIDEREAL_YEAR,
+            estimate,
+        )
+    }
+}
+
+/// A sign of the sidereal zodiac, through which the sun passes in one solar
+/// month.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Rashi {
+    Mesh = 0,
+    Brikh = 1,
+    Mithun = 2,
+    Karak = 3,
+    Singh = 4,
+    Kanya = 5,
+    Tula = 6,
+    Brishchak = 7,
+    Dhan = 8,
+    Makar = 9,
+    Kumbh = 10,
+    Meen = 11,
+}
+
+impl Rashi {
+    /// All rashis in order, starting with Mesh.
+    pub const ALL: [Rashi; 12] = [
+        Rashi::Mesh,
+        Rashi::Brikh,
+        Rashi::Mithun,
+        Rashi::Karak,
+        Rashi::Singh,
+        Rashi::Kanya,
+        Rashi::Tula,
+        Rashi::Brishchak,
+        Rashi::Dhan,
+        Rashi::Makar,
+        Rashi::Kumbh,
+        Rashi::Meen,
+    ];
+
+    /// The sidereal longitude at which the rashi begins, in degrees.
+    pub fn longitude(self) -> f64 {
+        self as u8 as f64 * 30.0
+    }
+
+    /// The romanized Punjabi name of the rashi.
+    pub fn name(self) -> &'static str {
+        self.name_in(Script::Latin)
+    }
+
+    /// The Punjabi name of the rashi in the given script.
+    pub fn name_in(self, script: Script) -> &'static str {
+        let names: [&str; 12] = match script {
+            Script::Latin => [
+                "Mesh",
+                "Brikh",
+                "Mithun",
+                "Karak",
+                "Singh",
+                "Kanya",
+                "Tula",
+                "Brishchak",
+                "Dhan",
+                "Makar",
+                "Kumbh",
+                "Meen",
+            ],
+            Script::Gurmukhi => [
+                "ਮੇਖ",
+                "ਬ੍ਰਿਖ",
+                "ਮਿਥੁਨ",
+                "ਕਰਕ",
+                "ਸਿੰਘ",
+                "ਕੰਨਿਆ",
+                "ਤੁਲਾ",
+                "ਬ੍ਰਿਸ਼ਚਕ",
+                "ਧਨ",
+                "ਮਕਰ",
+                "ਕੁੰਭ",
+                "ਮੀਨ",
+            ],
+            Script::Shahmukhi => [
+                "میکھ",
+                "برکھ",
+                "متھن",
+                "کرک",
+                "سنگھ",
+                "کنیا",
+                "تلا",
+                "برشچک",
+                "دھن",
+                "مکر",
+                "کمبھ",
+                "مین",
+            ],
+            Script::Devanagari => [
+                "मेख",
+                "ब्रिख",
+                "मिथुन",
+                "करक",
+                "सिंघ",
+                "कन्या",
+                "तुला",
+                "ब्रिश्चक",
+                "धन",
+                "मकर",
+                "कुंभ",
+                "मीन",
+            ],
+        };
+        names[self as usize]
+    }
+
+    /// The rashi containing a sidereal longitude in degrees.
+    pub(crate) fn containing(longitude: f64) -> Self {
+        Rashi::ALL[(astro::normalize(longitude) / 30.0) as usize % 12]
+    }
+}
+
+impl fmt::Display for Rashi {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn instant(year: i32, month: u32, day: u32, hour: u32, minute: u32) -> f64 {
+        astro::julian_day(
+            Utc.with_ymd_and_hms(year, month, day, hour, minute, 0)
+                .unwrap(),
+        )
+    }
+
+    #[test]
+    fn test_lahiri_at_j2000() {
+        // The Lahiri ayanamsa was 23°51'25" at 12h TT on 1 January 2000.
+        let degrees = Ayanamsa::Lahiri.degrees_at(2451545.0);
+        assert!((degrees - 23.857).abs() < 0.01, "{degrees}");
+    }
+
+    #[test]
+    fn test_sankranti_rashis() {
+        // Published Lahiri sankrantis: Makar at 09:03 IST on 14 January
+        // 2025, and Mesh at 03:30 IST on 14 April 2025.
+        for (rashi, previous, julian_day) in [
+            (Rashi::Makar, Rashi::Dhan, instant(2025, 1, 14, 3, 33)),
+            (Rashi::Mesh, Rashi::Meen, instant(2025, 4, 13, 22, 0)),
+        ] {
+            let hour: f64 = 1.0 / 24.0;
+            let before = Ayanamsa::Lahiri.sun_longitude(julian_day - hour);
+            let after = Ayanamsa::Lahiri.sun_longitude(julian_day + hour);
+            assert_eq!(Rashi::containing(before), previous);
+            assert_eq!(Rashi::containing(after), rashi);
+
+            let ingress: f64 = Ayanamsa::Lahiri.ingress(rashi, julian_day - 1.0);
+            assert!(
+                (ingress - julian_day).abs() < 0.5 * hour,
+                "{rashi}: {ingress}"
+            );
+        }
+    }
+}
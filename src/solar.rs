@@ -0,0 +1,194 @@
+//! Sunrise, sunset and the hours of the gurdwara day that follow them.
+//!
+//! Amrit Vela, the hours before dawn given to Nitnem and Simran, ends at
+//! sunrise; Rehras Sahib is read at sunset and Kirtan Sohila before sleep.
+//! The times here are computed from the position of the sun, without any
+//! network access, and fall within a minute or so of published tables for
+//! modern dates. Sunrise and sunset are the instants the upper edge of the
+//! sun meets the horizon, lowered for the elevation of the observer.
+
+use std::ops::Range;
+
+use chrono::{DateTime, Duration, FixedOffset, NaiveDate, NaiveTime};
+
+use crate::{astro, Location, NanakshahiDate, NanakshahiError};
+
+/// The times of the sun on one day.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SunTimes {
+    /// Sunrise, or `None` if the sun does not rise or set that day.
+    pub sunrise: Option<DateTime<FixedOffset>>,
+    /// The instant the sun crosses the meridian.
+    pub solar_noon: DateTime<FixedOffset>,
+    /// Sunset, or `None` if the sun does not rise or set that day.
+    pub sunset: Option<DateTime<FixedOffset>>,
+}
+
+/// How the Amrit Vela window before sunrise is reckoned.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum AmritVela {
+    /// The last pahar of the night, a quarter of the time from sunset to
+    /// sunrise.
+    #[default]
+    LastPahar,
+    /// A fixed span ending at sunrise.
+    BeforeSunrise(Duration),
+}
+
+/// The times of the sun on a date at a location, in the location's time
+/// zone.
+///
+/// # Errors
+/// Returns [`NanakshahiError::YearOutOfRange`] if the date is outside the
+/// range supported by chrono.
+///
+/// # Examples
+/// ```
+/// use chrono::Timelike;
+/// use nanakshahi::{solar, Location, NanakshahiDate, NanakshahiMonth};
+///
+/// let vaisakhi = NanakshahiDate::new(557, NanakshahiMonth::Vaisakh, 1)?;
+/// let times = solar::sun_times(vaisakhi, &Location::AMRITSAR)?;
+/// let sunrise = times.sunrise.unwrap();
+/// assert_eq!((sunrise.hour(), sunrise.minute()), (6, 0));
+/// # Ok::<(), nanakshahi::NanakshahiError>(())
+/// ```
+pub fn sun_times(date: NanakshahiDate, location: &Location) -> Result<SunTimes, NanakshahiError> {
+    let gregorian: NaiveDate = date.to_gregorian()?;
+    let local = |julian_day: f64| -> Result<DateTime<FixedOffset>, NanakshahiError> {
+        astro::from_julian_day(julian_day)
+            .map(|instant| instant.with_timezone(&location.offset))
+            .ok_or(NanakshahiError::YearOutOfRange(date.year))
+    };
+
+    Ok(SunTimes {
+        sunrise: sun_event(gregorian, location, true)
+            .map(local)
+            .transpose()?,
+        solar_noon: local(solar_noon(gregorian, location))?,
+        sunset: sun_event(gregorian, location, false)
+            .map(local)
+            .transpose()?,
+    })
+}
+
+/// The Amrit Vela window that ends at sunrise on a date, or `None` if the
+/// sun does not rise or set.
+///
+/// # Errors
+/// Returns [`NanakshahiError::YearOutOfRange`] if the date is outside the
+/// range supported by chrono.
+///
+/// # Examples
+/// ```
+/// use chrono::Duration;
+/// use nanakshahi::solar::{self, AmritVela};
+/// use nanakshahi::{Location, NanakshahiDate, NanakshahiMonth};
+///
+/// let date = NanakshahiDate::new(557, NanakshahiMonth::Poh, 23)?;
+/// let window = solar::amrit_vela(date, &Location::AMRITSAR, AmritVela::LastPahar)?.unwrap();
+/// // Winter nights are long, so the last pahar lasts over three hours.
+/// assert!(window.end - window.start > Duration::hours(3));
+///
+/// let fixed = AmritVela::BeforeSunrise(Duration::hours(3));
+/// let window = solar::amrit_vela(date, &Location::AMRITSAR, fixed)?.unwrap();
+/// assert_eq!(window.end - window.start, Duration::hours(3));
+/// # Ok::<(), nanakshahi::NanakshahiError>(())
+/// ```
+pub fn amrit_vela(
+    date: NanakshahiDate,
+    location: &Location,
+    rule: AmritVela,
+) -> Result<Option<Range<DateTime<FixedOffset>>>, NanakshahiError> {
+    let Some(sunrise) = sun_times(date, location)?.sunrise else {
+        return Ok(None);
+    };
+
+    let start: DateTime<FixedOffset> = match rule {
+        AmritVela::LastPahar => {
+            let previous: NanakshahiDate = date
+                .checked_sub_days(1)
+                .ok_or(NanakshahiError::YearOutOfRange(date.year))?;
+            let Some(sunset) = sun_times(previous, location)?.sunset else {
+                return Ok(None);
+            };
+            sunrise - (sunrise - sunset) / 4
+        }
+        AmritVela::BeforeSunrise(duration) => sunrise - duration,
+    };
+    Ok(Some(start..sunrise))
+}
+
+/// The instant of solar noon on a civil day at a location.
+fn solar_noon(date: NaiveDate, location: &Location) -> f64 {
+    let offset: f64 = location.offset.local_minus_utc() as f64 / 86_400.0;
+    let noon: f64 = astro::julian_day_at(date, NaiveTime::MIN) + 0.5 - offset;
+    astro::solar_transit(noon, location.longitude)
+}
+
+/// The instant of sunrise (`rising`) or sunset on a civil day at a
+/// location, or `None` if the sun does not rise or set.
+pub(crate) fn sun_event(date: NaiveDate, location: &Location, rising: bool) -> Option<f64> {
+    let noon: f64 = solar_noon(date, location);
+    let estimate: f64 = if rising { noon - 0.25 } else { noon + 0.25 };
+    astro::sun_altitude_time(
+        estimate,
+        location.latitude,
+        location.longitude,
+        astro::sunrise_altitude(location.elevation),
+        rising,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Timelike;
+
+    use crate::NanakshahiMonth;
+
+    fn time(instant: Option<DateTime<FixedOffset>>) -> (u32, u32) {
+        let instant = instant.unwrap();
+        (instant.hour(), instant.minute())
+    }
+
+    #[test]
+    fn test_sun_times() {
+        // 14 April 2025 in Amritsar.
+        let date = NanakshahiDate::new(557, NanakshahiMonth::Vaisakh, 1).unwrap();
+        let times = sun_times(date, &Location::AMRITSAR).unwrap();
+
+        assert_eq!(time(times.sunrise), (6, 0));
+        assert_eq!(time(Some(times.solar_noon)), (12, 30));
+        assert_eq!(time(times.sunset), (19, 1));
+        assert_eq!(times.solar_noon.offset(), &Location::AMRITSAR.offset);
+    }
+
+    #[test]
+    fn test_polar_day() {
+        // Midsummer in Tromsø, where the sun does not set.
+        let tromso = Location::new(69.65, 18.96, 0.0, FixedOffset::east_opt(7200).unwrap());
+        let date = NanakshahiDate::new(557, NanakshahiMonth::Harh, 7).unwrap();
+        let times = sun_times(date, &tromso).unwrap();
+
+        assert_eq!((times.sunrise, times.sunset), (None, None));
+        assert_eq!(time(Some(times.solar_noon)), (12, 46));
+        assert_eq!(amrit_vela(date, &tromso, AmritVela::LastPahar), Ok(None));
+    }
+
+    #[test]
+    fn test_amrit_vela() {
+        let date = NanakshahiDate::new(557, NanakshahiMonth::Harh, 7).unwrap();
+        let window = amrit_vela(date, &Location::AMRITSAR, AmritVela::LastPahar)
+            .unwrap()
+            .unwrap();
+
+        // Summer nights in Amritsar last about ten hours.
+        let length: i64 = (window.end - window.start).num_minutes();
+        assert!((145..160).contains(&length), "{length}");
+        assert_eq!(
+            Some(window.end),
+            sun_times(date, &Location::AMRITSAR).unwrap().sunrise
+        );
+    }
+}
@@ -0,0 +1,392 @@
+use std::fmt;
+
+use chrono::{Datelike, Duration, NaiveDate};
+
+use crate::format::DelayedFormat;
+use crate::location::IST;
+use crate::parse;
+use crate::sangrand::{self, SangrandSystem};
+use crate::{
+    Ayanamsa, NanakshahiDate, NanakshahiError, NanakshahiMonth, NanakshahiWeekday, ParseError,
+    Script,
+};
+
+/// A version of the Nanakshahi calendar, which decides where each month
+/// begins.
+///
+/// The original calendar of 2003 fixes every month to the tropical year, with
+/// the year beginning on 14 March. The amendment adopted by the SGPC in 2010
+/// returned the months to the Bikrami solar calendar, in which each month
+/// begins on the day of the sidereal sankranti in India Standard Time and
+/// lasts between 29 and 32 days. The two Bikrami-based variants share their
+/// month boundaries and differ in which observances are reckoned by the moon,
+/// as described in the [`observances`](crate::observances) module.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub enum CalendarVariant {
+    /// The calendar as introduced in 2003, with fixed month lengths.
+    #[default]
+    Original2003,
+    /// The calendar as amended by the SGPC in 2010.
+    Amended2010,
+    /// The traditional Bikrami solar calendar with Nanakshahi year numbers.
+    Bikrami,
+}
+
+impl CalendarVariant {
+    /// All variants.
+    pub const ALL: [CalendarVariant; 3] = [
+        CalendarVariant::Original2003,
+        CalendarVariant::Amended2010,
+        CalendarVariant::Bikrami,
+    ];
+
+    /// Whether months begin at the sidereal sankranti.
+    pub fn is_sidereal(self) -> bool {
+        self != CalendarVariant::Original2003
+    }
+
+    /// Convert a Gregorian date to a Nanakshahi date under this variant.
+    ///
+    /// # Errors
+    /// Returns [`NanakshahiError::InvalidGregorianDate`] if the Gregorian date
+    /// does not exist or is outside the range supported by chrono.
+    ///
+    /// # Examples
+    /// ```
+    /// use nanakshahi::{CalendarVariant, NanakshahiMonth};
+    ///
+    /// // Vaisakhi 2024 fell on 13 April, a day before 1 Vaisakh in the
+    /// // original calendar.
+    /// let date = CalendarVariant::Amended2010.to(2024, 4, 13)?;
+    /// assert_eq!((date.month(), date.day()), (NanakshahiMonth::Vaisakh, 1));
+    ///
+    /// let date = CalendarVariant::Original2003.to(2024, 4, 13)?;
+    /// assert_eq!((date.month(), date.day()), (NanakshahiMonth::Chet, 31));
+    /// # Ok::<(), nanakshahi::NanakshahiError>(())
+    /// ```
+    pub fn to(self, year: i32, month: u8, day: u8) -> Result<VariantDate, NanakshahiError> {
+        let original: NanakshahiDate = crate::to(year, month, day)?;
+        if !self.is_sidereal() {
+            return VariantDate::from_original(original);
+        }
+
+        let date: NaiveDate = original.to_gregorian()?;
+        let mut start: (i32, NanakshahiMonth, NaiveDate) = self.previous_month_start(original)?;
+        for (year, month) in months_from(original.year, NanakshahiMonth::Chet).take(13) {
+            let month_start: NaiveDate = month_start(year, month)?;
+            if month_start > date {
+                break;
+            }
+            start = (year, month, month_start);
+        }
+
+        let (year, month, start) = start;
+        let year_start: NaiveDate = month_start(year, NanakshahiMonth::Chet)?;
+        Ok(VariantDate {
+            year,
+            month,
+            day: ((date - start).num_days() + 1) as u8,
+            ordinal: ((date - year_start).num_days() + 1) as u32,
+            variant: self,
+            gregorian: date,
+        })
+    }
+
+    /// Convert a Nanakshahi date under this variant to a Gregorian date.
+    ///
+    /// # Errors
+    /// Returns [`NanakshahiError::InvalidMonth`] or
+    /// [`NanakshahiError::InvalidDay`] if the date does not exist under this
+    /// variant, or [`NanakshahiError::YearOutOfRange`] if the Gregorian date
+    /// is outside the range supported by chrono.
+    ///
+    /// # Examples
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use nanakshahi::CalendarVariant;
+    ///
+    /// // Sawan had 32 days in 557 under the Bikrami solar calendar.
+    /// let date = CalendarVariant::Bikrami.from(557, 5, 32)?;
+    /// assert_eq!(date, NaiveDate::from_ymd_opt(2025, 8, 16).unwrap());
+    /// assert!(CalendarVariant::Original2003.from(557, 5, 32).is_err());
+    /// # Ok::<(), nanakshahi::NanakshahiError>(())
+    /// ```
+    pub fn from(self, year: i32, month: u8, day: u8) -> Result<NaiveDate, NanakshahiError> {
+        if !self.is_sidereal() {
+            return crate::from(year, month, day);
+        }
+
+        let days: u8 = self.days_in_month(year, month)?;
+        if day == 0 || day > days {
+            return Err(NanakshahiError::InvalidDay { year, month, day });
+        }
+        let start: NaiveDate = month_start(year, NanakshahiMonth::try_from(month)?)?;
+        Ok(start + Duration::days(day as i64 - 1))
+    }
+
+    /// Parse a date under this variant, in any of the forms that
+    /// [`NanakshahiDate`] parses from a string.
+    ///
+    /// # Errors
+    /// Returns a [`ParseError`] if the input does not match any form, or if
+    /// the date does not exist under this variant.
+    ///
+    /// # Examples
+    /// ```
+    /// use nanakshahi::{CalendarVariant, NanakshahiWeekday};
+    ///
+    /// let date = CalendarVariant::Bikrami.parse("32 Sawan 557")?;
+    /// assert_eq!(date.weekday(), NanakshahiWeekday::Shanivar);
+    /// assert!(CalendarVariant::Original2003.parse("557-05-32").is_err());
+    /// # Ok::<(), nanakshahi::ParseError>(())
+    /// ```
+    pub fn parse(self, s: &str) -> Result<VariantDate, ParseError> {
+        parse::parse_any_in(s, self)
+    }
+
+    /// The number of days in a month under this variant.
+    ///
+    /// # Errors
+    /// Returns [`NanakshahiError::InvalidMonth`] if the month is not between 1
+    /// and 12, or [`NanakshahiError::YearOutOfRange`] if the year cannot be
+    /// converted.
+    pub fn days_in_month(self, year: i32, month: u8) -> Result<u8, NanakshahiError> {
+        let month: NanakshahiMonth = NanakshahiMonth::try_from(month)?;
+        if !self.is_sidereal() {
+            return Ok(month.days(year));
+        }
+
+        let (next_year, next_month) = months_from(year, month)
+            .nth(1)
+            .ok_or(NanakshahiError::YearOutOfRange(year))?;
+        let days: i64 =
+            (month_start(next_year, next_month)? - month_start(year, month)?).num_days();
+        Ok(days as u8)
+    }
+
+    /// The start of Phaggan in the year before a date's original year.
+    fn previous_month_start(
+        self,
+        date: NanakshahiDate,
+    ) -> Result<(i32, NanakshahiMonth, NaiveDate), NanakshahiError> {
+        let year: i32 = date
+            .year
+            .checked_sub(1)
+            .ok_or(NanakshahiError::YearOutOfRange(date.year))?;
+        Ok((
+            year,
+            NanakshahiMonth::Phaggan,
+            month_start(year, NanakshahiMonth::Phaggan)?,
+        ))
+    }
+}
+
+/// A date under a [`CalendarVariant`], as returned by [`CalendarVariant::to`].
+///
+/// The weekday and day of the year are those of the Gregorian date the day
+/// falls on, so they hold under every variant.
+///
+/// # Examples
+/// ```
+/// use chrono::NaiveDate;
+/// use nanakshahi::{CalendarVariant, NanakshahiMonth, NanakshahiWeekday};
+///
+/// // Sawan had 32 days in 557 under the Bikrami solar calendar.
+/// let date = CalendarVariant::Bikrami.to(2025, 8, 16)?;
+/// assert_eq!((date.month(), date.day()), (NanakshahiMonth::Sawan, 32));
+/// assert_eq!(date.weekday(), NanakshahiWeekday::Shanivar);
+/// assert_eq!(date.to_gregorian(), NaiveDate::from_ymd_opt(2025, 8, 16).unwrap());
+/// assert_eq!(date.to_string(), "557-05-32");
+/// # Ok::<(), nanakshahi::NanakshahiError>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct VariantDate {
+    year: i32,
+    month: NanakshahiMonth,
+    day: u8,
+    ordinal: u32,
+    variant: CalendarVariant,
+    gregorian: NaiveDate,
+}
+
+impl VariantDate {
+    /// A date of the original calendar.
+    pub(crate) fn from_original(date: NanakshahiDate) -> Result<Self, NanakshahiError> {
+        Ok(VariantDate {
+            year: date.year,
+            month: date.month,
+            day: date.day,
+            ordinal: date.ordinal(),
+            variant: CalendarVariant::Original2003,
+            gregorian: date.to_gregorian()?,
+        })
+    }
+
+    /// The year, numbered astronomically so that the year before 0 is -1.
+    pub fn year(&self) -> i32 {
+        self.year
+    }
+
+    /// The month.
+    pub fn month(&self) -> NanakshahiMonth {
+        self.month
+    }
+
+    /// The day of the month, starting from 1.
+    pub fn day(&self) -> u8 {
+        self.day
+    }
+
+    /// The variant the date belongs to.
+    pub fn variant(&self) -> CalendarVariant {
+        self.variant
+    }
+
+    /// The day of the year, starting from 1 on 1 Chet of the variant.
+    pub fn ordinal(&self) -> u32 {
+        self.ordinal
+    }
+
+    /// The day of the week.
+    pub fn weekday(&self) -> NanakshahiWeekday {
+        self.gregorian.weekday().into()
+    }
+
+    /// The Gregorian date.
+    pub fn to_gregorian(&self) -> NaiveDate {
+        self.gregorian
+    }
+
+    /// The date in words in the given script, such as "1 Vaisakh 557".
+    pub fn to_string_in(&self, script: Script) -> String {
+        self.format_in("%-d %B %Y", script).to_string()
+    }
+
+    /// Format the date with a `strftime`-style pattern in Latin script.
+    ///
+    /// See the [`format`](crate::format) module for the specifiers.
+    pub fn format<'a>(&self, pattern: &'a str) -> DelayedFormat<'a> {
+        self.format_in(pattern, Script::Latin)
+    }
+
+    /// Format the date with a `strftime`-style pattern in the given script.
+    pub fn format_in<'a>(&self, pattern: &'a str, script: Script) -> DelayedFormat<'a> {
+        DelayedFormat::from_variant(*self, pattern, script)
+    }
+}
+
+impl fmt::Display for VariantDate {
+    /// Writes the date as year-month-day, such as "557-05-32".
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.format("%F"))
+    }
+}
+
+/// The Gregorian date on which a month begins in the sidereal variants.
+fn month_start(year: i32, month: NanakshahiMonth) -> Result<NaiveDate, NanakshahiError> {
+    sangrand::sangrand(year, month, SangrandSystem::Sidereal(Ayanamsa::Lahiri))?
+        .sankranti_date(IST)
+        .ok_or(NanakshahiError::YearOutOfRange(year))
+}
+
+/// Successive months from a year and month onwards.
+fn months_from(year: i32, month: NanakshahiMonth) -> impl Iterator<Item = (i32, NanakshahiMonth)> {
+    (month as i64 - 1..).map_while(move |index: i64| {
+        let year: i32 = i32::try_from(year as i64 + index.div_euclid(12)).ok()?;
+        Some((year, NanakshahiMonth::ALL[index.rem_euclid(12) as usize]))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Datelike;
+
+    fn naive(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn test_original_matches_crate() {
+        let variant = CalendarVariant::Original2003;
+
+        let date = variant.to(2025, 3, 14).unwrap();
+        assert_eq!(
+            (date.year(), date.month(), date.day()),
+            (557, NanakshahiMonth::Chet, 1)
+        );
+        assert_eq!(date.to_gregorian(), naive(2025, 3, 14));
+        assert_eq!(variant.from(557, 12, 30), crate::from(557, 12, 30));
+        assert_eq!(variant.days_in_month(555, 12), Ok(31));
+    }
+
+    #[test]
+    fn test_sidereal_month_lengths() {
+        let variant = CalendarVariant::Amended2010;
+        let days: Vec<u8> = (1..=12)
+            .map(|month| variant.days_in_month(557, month).unwrap())
+            .collect();
+
+        // The year runs from 14 March 2025 to 14 March 2026.
+        assert_eq!(days.iter().map(|&days| days as u32).sum::<u32>(), 366);
+        assert!(days.iter().all(|days| (29..=32).contains(days)), "{days:?}");
+        assert_eq!(days[4], 32);
+    }
+
+    #[test]
+    fn test_sidereal_round_trip() {
+        let variant = CalendarVariant::Bikrami;
+        let mut date = naive(2024, 3, 1);
+        while date < naive(2026, 4, 1) {
+            let nanakshahi = variant
+                .to(date.year(), date.month() as u8, date.day() as u8)
+                .unwrap();
+            assert_eq!(
+                variant.from(
+                    nanakshahi.year(),
+                    nanakshahi.month().number(),
+                    nanakshahi.day()
+                ),
+                Ok(date)
+            );
+            assert_eq!(nanakshahi.to_gregorian(), date);
+            date = date.succ_opt().unwrap();
+        }
+    }
+
+    #[test]
+    fn test_sidereal_year_start() {
+        // Makar Sankranti and Maghi on 14 January 2025.
+        let maghi = CalendarVariant::Amended2010.to(2025, 1, 14).unwrap();
+        assert_eq!(
+            (maghi.year(), maghi.month(), maghi.day()),
+            (556, NanakshahiMonth::Magh, 1)
+        );
+
+        // The sidereal year begins on the day of the Meen sankranti.
+        let new_year = CalendarVariant::Bikrami.to(2025, 3, 14).unwrap();
+        assert_eq!(
+            (new_year.year(), new_year.month(), new_year.day()),
+            (557, NanakshahiMonth::Chet, 1)
+        );
+        assert_eq!(
+            CalendarVariant::Bikrami.from(557, 13, 1),
+            Err(NanakshahiError::InvalidMonth(13))
+        );
+    }
+
+    #[test]
+    fn test_sidereal_weekday_and_ordinal() {
+        // Vaisakhi on Shanivar 13 April 2024, a day before 1 Vaisakh in the
+        // original calendar.
+        let vaisakhi = CalendarVariant::Amended2010.to(2024, 4, 13).unwrap();
+        assert_eq!(vaisakhi.to_string(), "556-02-01");
+        assert_eq!(vaisakhi.weekday(), NanakshahiWeekday::Shanivar);
+        assert_eq!(vaisakhi.ordinal(), 31);
+        assert_eq!(vaisakhi.format("%A %j").to_string(), "Shanivar 031");
+
+        let maghi = CalendarVariant::Bikrami.to(2025, 1, 14).unwrap();
+        assert_eq!(maghi.weekday(), NanakshahiWeekday::Mangalvar);
+        assert_eq!(maghi.ordinal(), 307);
+    }
+}
@@ -0,0 +1,145 @@
+use std::fmt;
+
+use chrono::Weekday;
+
+use crate::Script;
+
+/// A day of the week, named as in a Punjabi jantri.
+///
+/// The week starts on Aitvar (Sunday).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum NanakshahiWeekday {
+    Aitvar = 0,
+    Somvar = 1,
+    Mangalvar = 2,
+    Budhvar = 3,
+    Veervar = 4,
+    Shukarvar = 5,
+    Shanivar = 6,
+}
+
+impl NanakshahiWeekday {
+    /// All weekdays in order, starting with Aitvar.
+    pub const ALL: [NanakshahiWeekday; 7] = [
+        NanakshahiWeekday::Aitvar,
+        NanakshahiWeekday::Somvar,
+        NanakshahiWeekday::Mangalvar,
+        NanakshahiWeekday::Budhvar,
+        NanakshahiWeekday::Veervar,
+        NanakshahiWeekday::Shukarvar,
+        NanakshahiWeekday::Shanivar,
+    ];
+
+    /// Days since Aitvar, from 0 to 6.
+    pub fn num_days_from_aitvar(self) -> u8 {
+        self as u8
+    }
+
+    /// The romanized Punjabi name of the weekday.
+    pub fn name(self) -> &'static str {
+        match self {
+            NanakshahiWeekday::Aitvar => "Aitvar",
+            NanakshahiWeekday::Somvar => "Somvar",
+            NanakshahiWeekday::Mangalvar => "Mangalvar",
+            NanakshahiWeekday::Budhvar => "Budhvar",
+            NanakshahiWeekday::Veervar => "Veervar",
+            NanakshahiWeekday::Shukarvar => "Shukarvar",
+            NanakshahiWeekday::Shanivar => "Shanivar",
+        }
+    }
+
+    /// The English name of the weekday.
+    pub fn english_name(self) -> &'static str {
+        match self {
+            NanakshahiWeekday::Aitvar => "Sunday",
+            NanakshahiWeekday::Somvar => "Monday",
+            NanakshahiWeekday::Mangalvar => "Tuesday",
+            NanakshahiWeekday::Budhvar => "Wednesday",
+            NanakshahiWeekday::Veervar => "Thursday",
+            NanakshahiWeekday::Shukarvar => "Friday",
+            NanakshahiWeekday::Shanivar => "Saturday",
+        }
+    }
+
+    /// The name of the weekday in Gurmukhi script.
+    pub fn gurmukhi_name(self) -> &'static str {
+        self.name_in(Script::Gurmukhi)
+    }
+
+    /// The Punjabi name of the weekday in the given script.
+    pub fn name_in(self, script: Script) -> &'static str {
+        let names: [&str; 7] = match script {
+            Script::Latin => return self.name(),
+            Script::Gurmukhi => [
+                "ਐਤਵਾਰ",
+                "ਸੋਮਵਾਰ",
+                "ਮੰਗਲਵਾਰ",
+                "ਬੁੱਧਵਾਰ",
+                "ਵੀਰਵਾਰ",
+                "ਸ਼ੁੱਕਰਵਾਰ",
+                "ਸ਼ਨੀਵਾਰ",
+            ],
+            Script::Shahmukhi => [
+                "اتوار",
+                "سوموار",
+                "منگلوار",
+                "بدھوار",
+                "ویروار",
+                "شکروار",
+                "سنیچروار",
+            ],
+            Script::Devanagari => [
+                "ऐतवार",
+                "सोमवार",
+                "मंगलवार",
+                "बुधवार",
+                "वीरवार",
+                "शुक्रवार",
+                "शनिवार",
+            ],
+        };
+        names[self as usize]
+    }
+}
+
+impl From<Weekday> for NanakshahiWeekday {
+    fn from(weekday: Weekday) -> Self {
+        NanakshahiWeekday::ALL[weekday.num_days_from_sunday() as usize]
+    }
+}
+
+impl From<NanakshahiWeekday> for Weekday {
+    fn from(weekday: NanakshahiWeekday) -> Self {
+        match weekday {
+            NanakshahiWeekday::Aitvar => Weekday::Sun,
+            NanakshahiWeekday::Somvar => Weekday::Mon,
+            NanakshahiWeekday::Mangalvar => Weekday::Tue,
+            NanakshahiWeekday::Budhvar => Weekday::Wed,
+            NanakshahiWeekday::Veervar => Weekday::Thu,
+            NanakshahiWeekday::Shukarvar => Weekday::Fri,
+            NanakshahiWeekday::Shanivar => Weekday::Sat,
+        }
+    }
+}
+
+impl fmt::Display for NanakshahiWeekday {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_chrono_round_trip() {
+        for weekday in NanakshahiWeekday::ALL {
+            assert_eq!(NanakshahiWeekday::from(Weekday::from(weekday)), weekday);
+        }
+        assert_eq!(
+            NanakshahiWeekday::from(Weekday::Thu),
+            NanakshahiWeekday::Veervar
+        );
+    }
+}